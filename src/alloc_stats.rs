@@ -0,0 +1,143 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+    time::{Duration, Instant},
+};
+
+// Wraps the system allocator and counts every allocation.
+// Only installed when the `count-allocations` feature is enabled.
+pub struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES_ALLOCATED.fetch_add(size, Relaxed);
+    let current = CURRENT_BYTES.fetch_add(size, Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT_BYTES.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // counted as freeing the old block and allocating the new one
+            CURRENT_BYTES.fetch_sub(layout.size(), Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+// Measures the time and allocations between `start` and `finish`
+pub struct Measure {
+    start: Instant,
+    allocations: usize,
+    bytes_allocated: usize,
+}
+
+impl Measure {
+    pub fn start() -> Self {
+        PEAK_BYTES.store(CURRENT_BYTES.load(Relaxed), Relaxed);
+        Measure {
+            start: Instant::now(),
+            allocations: ALLOCATIONS.load(Relaxed),
+            bytes_allocated: BYTES_ALLOCATED.load(Relaxed),
+        }
+    }
+
+    pub fn finish(self) -> Report {
+        Report {
+            elapsed: self.start.elapsed(),
+            allocations: ALLOCATIONS.load(Relaxed) - self.allocations,
+            bytes_allocated: BYTES_ALLOCATED.load(Relaxed) - self.bytes_allocated,
+            peak_bytes: PEAK_BYTES.load(Relaxed),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Report {
+    pub elapsed: Duration,
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    pub peak_bytes: usize,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "took {:.2?}, {} allocations totalling {}, peak heap {}",
+            self.elapsed,
+            self.allocations,
+            Bytes(self.bytes_allocated),
+            Bytes(self.peak_bytes)
+        ))
+    }
+}
+
+struct Bytes(usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let units = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+        let mut size = self.0 as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < units.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        if unit == 0 {
+            f.write_fmt(format_args!("{} {}", self.0, units[unit]))
+        } else {
+            f.write_fmt(format_args!("{:.1} {}", size, units[unit]))
+        }
+    }
+}
+
+#[test]
+fn display_bytes() {
+    assert_eq!(Bytes(0).to_string(), "0 B");
+    assert_eq!(Bytes(1023).to_string(), "1023 B");
+    assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+    assert_eq!(Bytes(3 * 1024 * 1024 * 1024).to_string(), "3.0 GiB");
+}
+
+#[test]
+fn counts_allocations() {
+    let measure = Measure::start();
+    let v = vec![0_u8; 4096];
+    let report = measure.finish();
+    drop(v);
+
+    assert!(report.allocations >= 1);
+    assert!(report.bytes_allocated >= 4096);
+    assert!(report.peak_bytes >= 4096);
+}
@@ -0,0 +1,97 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::solutions;
+
+pub const CACHE_DIR: &str = ".cache/answers";
+
+// Answers are stored one per file, named after the year, the day, the part,
+// a hash of the input and a hash of the day's source along with the modules
+// the solutions share. Changing either the input or the solution gives a new
+// key, so stale answers are never returned.
+pub struct AnswerCache {
+    dir: PathBuf,
+}
+
+impl AnswerCache {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        AnswerCache {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    fn path(&self, year: u32, day: u32, part: u32, input: &str) -> PathBuf {
+        let version = solutions::version(year, day);
+        self.dir.join(format!(
+            "{}-{:02}-{}-{:016x}-{:016x}",
+            year,
+            day,
+            part,
+            fnv1a(input.as_bytes()),
+            fnv1a(version.as_bytes())
+        ))
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32, input: &str) -> Option<String> {
+        fs::read_to_string(self.path(year, day, part, input)).ok()
+    }
+
+    pub fn insert(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        input: &str,
+        answer: &str,
+    ) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(year, day, part, input), answer)
+    }
+
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
+
+// std's DefaultHasher isn't guaranteed to be stable between releases, which
+// would silently invalidate the cache, so use FNV-1a instead
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[test]
+fn fnv1a_known_values() {
+    assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+    assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+}
+
+#[test]
+fn round_trip() {
+    let dir = std::env::temp_dir().join(format!("aoc-cache-test-{}", std::process::id()));
+    let cache = AnswerCache::new(&dir);
+
+    assert_eq!(cache.get(2023, 1, 1, "1abc2"), None);
+    cache.insert(2023, 1, 1, "1abc2", "12").unwrap();
+    assert_eq!(cache.get(2023, 1, 1, "1abc2"), Some("12".to_string()));
+    assert_eq!(cache.get(2023, 1, 1, "1abc3"), None);
+    assert_eq!(cache.get(2023, 1, 2, "1abc2"), None);
+
+    cache.clear().unwrap();
+    assert_eq!(cache.get(2023, 1, 1, "1abc2"), None);
+    cache.clear().unwrap();
+}
+
+#[test]
+fn version_covers_shared_modules() {
+    let version = solutions::version(2023, 1);
+    assert!(version.starts_with(solutions::source(2023, 1).unwrap()));
+    assert!(version.contains(include_str!("parsing.rs")));
+    assert_eq!(solutions::version(2023, 26), "");
+}
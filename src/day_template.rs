@@ -1,4 +1,4 @@
-pub const DAY_STR: &str = "day_{}";
+pub const DAY_STR: &str = "{}/day_{}";
 
 pub mod part_1 {
 
@@ -0,0 +1,51 @@
+use std::fmt;
+
+// Receives the human readable steps a solution took to reach its answer.
+// Solutions write to it through `explain!`, which only formats the step when
+// the sink keeps it. The arguments themselves are still evaluated, so a step
+// that's costly to build should be behind `enabled`.
+pub trait Explain {
+    fn step(&mut self, args: fmt::Arguments);
+
+    // whether the steps are kept at all
+    fn enabled(&self) -> bool {
+        true
+    }
+}
+
+#[macro_export]
+macro_rules! explain {
+    ($sink:expr, $($arg:tt)*) => {
+        $crate::explain::Explain::step(&mut *$sink, format_args!($($arg)*))
+    };
+}
+
+// discards every step, used by the plain `solution` functions
+pub struct Silent;
+
+impl Explain for Silent {
+    fn step(&mut self, _args: fmt::Arguments) {}
+
+    fn enabled(&self) -> bool {
+        false
+    }
+}
+
+// prints every step on its own indented line
+pub struct Printer;
+
+impl Explain for Printer {
+    fn step(&mut self, args: fmt::Arguments) {
+        println!("    {}", args);
+    }
+}
+
+// keeps every step, mostly useful for tests
+#[derive(Debug, Default)]
+pub struct Collect(pub Vec<String>);
+
+impl Explain for Collect {
+    fn step(&mut self, args: fmt::Arguments) {
+        self.0.push(args.to_string());
+    }
+}
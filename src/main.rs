@@ -1,118 +1,378 @@
 #![allow(dead_code, clippy::needless_range_loop, clippy::ptr_arg)]
 
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-mod day_21;
-mod day_22;
-mod day_23;
-mod day_24;
-mod day_25;
-
-use std::env;
-
-use day_01 as day;
+#[cfg(feature = "count-allocations")]
+mod alloc_stats;
+mod cache;
+mod explain;
+mod parsing;
+mod server;
+mod solutions;
+mod streaming;
+mod validate;
+mod y2023;
+
+use std::{env, fs, io::BufReader};
+
+use tracing::{info_span, Level};
+use tracing_subscriber::{filter::Targets, prelude::*};
+
+use y2023::day_01 as day;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: alloc_stats::CountingAlloc = alloc_stats::CountingAlloc;
+
+#[derive(Debug)]
+struct Options {
+    year: u32,
+    day_parts: Vec<String>,
+    trace: Option<Level>,
+    trace_days: Vec<u32>,
+    explain: bool,
+    serve: bool,
+    port: u16,
+    no_cache: bool,
+    clear_cache: bool,
+    check: bool,
+    stream: bool,
+    cubes: bool,
+    bag: Option<String>,
+    at_least: Option<usize>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            year: solutions::latest_year(),
+            day_parts: Vec::new(),
+            trace: None,
+            trace_days: Vec::new(),
+            explain: false,
+            serve: false,
+            port: 3000,
+            no_cache: false,
+            clear_cache: false,
+            check: false,
+            stream: false,
+            cubes: false,
+            bag: None,
+            at_least: None,
+        }
+    }
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Self {
+        let mut options = Options::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--trace" => {
+                    let level = args.next().expect("Expected a level after `--trace`");
+                    options.trace = Some(level.parse().expect("Failed to parse trace level"));
+                }
+                "--trace-day" => {
+                    let day = args.next().expect("Expected a day after `--trace-day`");
+                    options
+                        .trace_days
+                        .push(day.parse().expect("Failed to parse day"));
+                }
+                "--explain" => options.explain = true,
+                "--no-cache" => options.no_cache = true,
+                "--stream" => options.stream = true,
+                "cache" => match args.next().as_deref() {
+                    Some("clear") => options.clear_cache = true,
+                    other => panic!("Unknown cache command: {:?}", other),
+                },
+                "serve" => options.serve = true,
+                "check" => options.check = true,
+                "cubes" => options.cubes = true,
+                "--bag" => {
+                    options.bag = Some(args.next().expect("Expected the cubes after `--bag`"));
+                }
+                "--at-least" => {
+                    let games = args.next().expect("Expected a number after `--at-least`");
+                    options.at_least = Some(games.parse().expect("Failed to parse number"));
+                }
+                "--port" => {
+                    let port = args.next().expect("Expected a port after `--port`");
+                    options.port = port.parse().expect("Failed to parse port");
+                }
+                year if year.len() == 4 && year.parse::<u32>().is_ok() => {
+                    options.year = year.parse().unwrap();
+                }
+                _ => options.day_parts.push(arg),
+            }
+        }
+
+        options
+    }
+}
+
+// prints trace events to stderr, either for every day or only the given days
+fn init_tracing(level: Level, year: u32, days: &[u32]) {
+    let targets = if days.is_empty() {
+        Targets::new().with_target("advent_of_code", level)
+    } else {
+        days.iter().fold(Targets::new(), |targets, day| {
+            targets.with_target(format!("advent_of_code::y{}::day_{:02}", year, day), level)
+        })
+    };
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+        .with(targets)
+        .init();
+}
 
 fn main() {
-    let day_parts = env::args().collect::<Vec<_>>();
+    let options = Options::parse(env::args().skip(1));
+
+    if let Some(level) = options.trace {
+        init_tracing(level, options.year, &options.trace_days);
+    }
+
+    if options.clear_cache {
+        cache::AnswerCache::new(cache::CACHE_DIR)
+            .clear()
+            .expect("Failed to clear the answer cache");
+        println!("Cleared {}", cache::CACHE_DIR);
+        return;
+    }
+
+    if options.check {
+        let to_check =
+            if options.day_parts.is_empty() || options.day_parts.contains(&"all".to_string()) {
+                // day 25 has no second part, so no input for it
+                solutions::registered()
+                    .filter(|(year, day, part)| *year == options.year && (*day, *part) != (25, 2))
+                    .map(|(_, day, part)| (day, part))
+                    .collect::<Vec<_>>()
+            } else {
+                options
+                    .day_parts
+                    .iter()
+                    .flat_map(|day_part| match day_part.split_once('-') {
+                        Some((day, part)) => vec![(
+                            day.parse().expect("Failed to parse day"),
+                            part.parse().expect("Failed to parse part"),
+                        )],
+                        None => {
+                            let day = day_part.parse().expect("Failed to parse day");
+                            vec![(day, 1), (day, 2)]
+                        }
+                    })
+                    .collect()
+            };
+
+        let invalid = to_check
+            .into_iter()
+            .filter(|(day, part)| !check_input(options.year, *day, *part))
+            .count();
+
+        if invalid > 0 {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if options.cubes {
+        query_cubes(&options);
+        return;
+    }
+
+    if options.serve {
+        server::serve(options.port);
+        return;
+    }
+
+    let day_parts = &options.day_parts;
 
     if day_parts.contains(&"all".to_string()) {
-        for day in 1..=25 {
-            for part in 1..=2 {
-                run_solution(day, part);
-            }
+        for (year, day, part) in solutions::registered().filter(|(year, ..)| *year == options.year)
+        {
+            run_solution(year, day, part, &options);
         }
     } else {
         for day_part in day_parts {
             if let Some((day, part)) = day_part.split_once('-') {
                 run_solution(
+                    options.year,
                     day.parse().expect("Failed to parse day"),
                     part.parse().expect("Failed to parse part"),
+                    &options,
                 );
             }
         }
     }
 }
 
-fn run_solution(day: u32, part: u32) {
-    assert!((1..=25).contains(&day));
-    assert!((1..=2).contains(&part));
-    let input =
-        std::fs::read_to_string(format!("inputs/day_{:02}/part_{}/input.txt", day, part)).unwrap();
+// prints every problem with the input for a part, returns whether it's valid
+fn check_input(year: u32, day: u32, part: u32) -> bool {
+    let validator = solutions::validator(year, day)
+        .unwrap_or_else(|| panic!("Invalid year: {year}, day: {day}, or part: {part}"));
+    let path = input_path(year, day, part);
+    let input = std::fs::read_to_string(&path).unwrap();
+
+    let problems = validator(&input);
+    if problems.is_empty() {
+        println!("{}: ok", path);
+    }
+    for problem in &problems {
+        match problem.line {
+            Some(line) => println!("{}:{}: {}", path, line, problem.message),
+            None => println!("{}: {}", path, problem.message),
+        }
+    }
+
+    problems.is_empty()
+}
+
+// answers questions about the day 2 games for bags other than the puzzle's
+fn query_cubes(options: &Options) {
+    use y2023::day_02;
+
+    // only 2023 has the cube game
+    if options.year != 2023 {
+        eprintln!(
+            "`cubes` asks about the games from 2023 day 2, there are none in {}",
+            options.year
+        );
+        std::process::exit(1);
+    }
+
+    let input = fs::read_to_string(input_path(options.year, 2, 1)).unwrap();
+    let games = day_02::read_games(&input);
+    let colours = day_02::colours(&games);
+
+    if let Some(bag) = &options.bag {
+        let bag = day_02::parse_bag(bag);
+        let possible = day_02::possible_games(&games, &bag)
+            .map(|game| game.id)
+            .collect::<Vec<_>>();
+        println!(
+            "{} of {} games are possible with {}, their ids sum to {}",
+            possible.len(),
+            games.len(),
+            day_02::format_cubes(&bag),
+            possible.iter().sum::<u32>()
+        );
+    }
+
+    if let Some(at_least) = options.at_least {
+        match day_02::smallest_bag(&games, at_least) {
+            Some(bag) => println!(
+                "smallest bag for {} games: {}",
+                at_least,
+                day_02::format_cubes(&bag)
+            ),
+            None => println!("there are only {} games", games.len()),
+        }
+    }
+
+    if options.bag.is_none() && options.at_least.is_none() {
+        for game in &games {
+            println!(
+                "Game {}: {}, power {}",
+                game.id,
+                day_02::format_cubes(&game.minimum_bag()),
+                game.power(&colours)
+            );
+        }
+    }
+}
+
+fn input_path(year: u32, day: u32, part: u32) -> String {
+    format!("inputs/{}/day_{:02}/part_{}/input.txt", year, day, part)
+}
+
+fn run_solution(year: u32, day: u32, part: u32, options: &Options) {
+    let path = input_path(year, day, part);
 
     let name = format!("{:02}-{}", day, part);
+    let _span = info_span!("solve", year, day, part).entered();
+
+    // large inputs are solved while they're read, and never cached since
+    // hashing them would mean reading them twice
+    let streaming_solver = solutions::streaming_solver(year, day, part).filter(|_| {
+        !options.explain
+            && (options.stream || fs::metadata(&path).unwrap().len() >= streaming::THRESHOLD_BYTES)
+    });
+    match streaming_solver {
+        Some(solver) => {
+            let input = BufReader::new(fs::File::open(&path).unwrap());
+            // reading the input is part of solving it when streaming
+            let (answer, report) = measured(|| solver(Box::new(input)));
+            println!("{}: {} (streamed)", name, answer);
+            print_report(report);
+        }
+        None => {
+            let input = fs::read_to_string(&path).unwrap();
+            print_solution(year, day, part, input, &name, options);
+        }
+    }
+}
+
+// Runs a solver, and with the `count-allocations` feature also measures the
+// time and allocations of just that call, not of reading the input or the
+// answer cache.
+fn measured(solve: impl FnOnce() -> String) -> (String, Option<String>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let measure = alloc_stats::Measure::start();
+        let answer = solve();
+        (answer, Some(measure.finish().to_string()))
+    }
+    #[cfg(not(feature = "count-allocations"))]
+    (solve(), None)
+}
+
+fn print_report(report: Option<String>) {
+    if let Some(report) = report {
+        println!("    solver {}", report);
+    }
+}
+
+fn print_solution(year: u32, day: u32, part: u32, input: String, name: &str, options: &Options) {
+    if options.explain {
+        println!("{}:", name);
+        match solutions::explainer(year, day, part) {
+            Some(explainer) => {
+                let (answer, report) = measured(|| explainer(input, &mut explain::Printer));
+                println!("{}: {}", name, answer);
+                print_report(report);
+                return;
+            }
+            None => println!("    no explanation available for this part"),
+        }
+    }
+
+    let solver = solutions::solver(year, day, part)
+        .unwrap_or_else(|| panic!("Invalid year: {year}, day: {day}, or part: {part}"));
+
+    if options.no_cache {
+        let (answer, report) = measured(|| solver(input));
+        println!("{}: {}", name, answer);
+        print_report(report);
+        return;
+    }
+
+    let cache = cache::AnswerCache::new(cache::CACHE_DIR);
+    if let Some(answer) = cache.get(year, day, part, &input) {
+        println!("{}: {} (cached)", name, answer);
+        if cfg!(feature = "count-allocations") {
+            println!("    solver not run, use --no-cache to measure it");
+        }
+        return;
+    }
 
-    match (day, part) {
-        (1, 1) => println!("{}: {}", name, day_01::part_1(input)),
-        (1, 2) => println!("{}: {}", name, day_01::part_2(input)),
-        (2, 1) => println!("{}: {}", name, day_02::part_1(input)),
-        (2, 2) => println!("{}: {}", name, day_02::part_2(input)),
-        (3, 1) => println!("{}: {}", name, day_03::part_1(input)),
-        (3, 2) => println!("{}: {}", name, day_03::part_2(input)),
-        (4, 1) => println!("{}: {}", name, day_04::part_1(input)),
-        (4, 2) => println!("{}: {}", name, day_04::part_2(input)),
-        (5, 1) => println!("{}: {}", name, day_05::part_1(input)),
-        (5, 2) => println!("{}: {}", name, day_05::part_2(input)),
-        (6, 1) => println!("{}: {}", name, day_06::part_1(input)),
-        (6, 2) => println!("{}: {}", name, day_06::part_2(input)),
-        (7, 1) => println!("{}: {}", name, day_07::part_1(input)),
-        (7, 2) => println!("{}: {}", name, day_07::part_2(input)),
-        (8, 1) => println!("{}: {}", name, day_08::part_1(input)),
-        (8, 2) => println!("{}: {}", name, day_08::part_2(input)),
-        (9, 1) => println!("{}: {}", name, day_09::part_1(input)),
-        (9, 2) => println!("{}: {}", name, day_09::part_2(input)),
-        (10, 1) => println!("{}: {}", name, day_10::part_1(input)),
-        (10, 2) => println!("{}: {}", name, day_10::part_2(input)),
-        (11, 1) => println!("{}: {}", name, day_11::part_1(input)),
-        (11, 2) => println!("{}: {}", name, day_11::part_2(input)),
-        (12, 1) => println!("{}: {}", name, day_12::part_1(input)),
-        (12, 2) => println!("{}: {}", name, day_12::part_2(input)),
-        (13, 1) => println!("{}: {}", name, day_13::part_1(input)),
-        (13, 2) => println!("{}: {}", name, day_13::part_2(input)),
-        (14, 1) => println!("{}: {}", name, day_14::part_1(input)),
-        (14, 2) => println!("{}: {}", name, day_14::part_2(input)),
-        (15, 1) => println!("{}: {}", name, day_15::part_1(input)),
-        (15, 2) => println!("{}: {}", name, day_15::part_2(input)),
-        (16, 1) => println!("{}: {}", name, day_16::part_1(input)),
-        (16, 2) => println!("{}: {}", name, day_16::part_2(input)),
-        (17, 1) => println!("{}: {}", name, day_17::part_1(input)),
-        (17, 2) => println!("{}: {}", name, day_17::part_2(input)),
-        (18, 1) => println!("{}: {}", name, day_18::part_1(input)),
-        (18, 2) => println!("{}: {}", name, day_18::part_2(input)),
-        (19, 1) => println!("{}: {}", name, day_19::part_1(input)),
-        (19, 2) => println!("{}: {}", name, day_19::part_2(input)),
-        (20, 1) => println!("{}: {}", name, day_20::part_1(input)),
-        (20, 2) => println!("{}: {}", name, day_20::part_2(input)),
-        (21, 1) => println!("{}: {}", name, day_21::part_1(input)),
-        (21, 2) => println!("{}: {}", name, day_21::part_2(input)),
-        (22, 1) => println!("{}: {}", name, day_22::part_1(input)),
-        (22, 2) => println!("{}: {}", name, day_22::part_2(input)),
-        (23, 1) => println!("{}: {}", name, day_23::part_1(input)),
-        (23, 2) => println!("{}: {}", name, day_23::part_2(input)),
-        (24, 1) => println!("{}: {}", name, day_24::part_1(input)),
-        (24, 2) => println!("{}: {}", name, day_24::part_2(input)),
-        (25, 1) => println!("{}: {}", name, day_25::part_1(input)),
-        (25, 2) => println!("{}: {}", name, day_25::part_2(input)),
-
-        (other_day, other_part) => panic!("Invalid day: {other_day}, or part: {other_part}"),
+    let owned = input.clone();
+    let (answer, report) = measured(|| solver(owned));
+    if let Err(err) = cache.insert(year, day, part, &input, &answer) {
+        eprintln!("Failed to cache answer for {}: {}", name, err);
     }
+    println!("{}: {}", name, answer);
+    print_report(report);
 }
 
 fn run_part_1() {
@@ -0,0 +1,196 @@
+use std::{fmt::Display, str::FromStr};
+
+use nom::{
+    character::complete::{char, digit1, line_ending, none_of, not_line_ending, space0, space1},
+    combinator::{map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::{many1, separated_list1},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+    Finish, IResult, Parser,
+};
+
+// Combinators shared by the days that parse their input with nom, along with
+// `parse` which runs one over a whole input and says where it went wrong.
+
+// a number without a sign, like `42`
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+// a number that may start with `-`, like `-42`
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(preceded(opt(char('-')), digit1)), str::parse)(input)
+}
+
+// numbers separated by any number of spaces, like `41 48  83 86 17`
+pub fn unsigned_list<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, unsigned)(input)
+}
+
+pub fn signed_list<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, signed)(input)
+}
+
+// a line like `seeds: 79 14 55 13` or `jqt: rhn xhk nvd`, returning the key
+// and the space separated values
+pub fn key_values<'a, T>(
+    value: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, Vec<T>)> {
+    separated_pair(
+        recognize(many1(none_of(":\r\n"))),
+        pair(char(':'), space0),
+        separated_list1(space1, value),
+    )
+}
+
+pub type Vector3<T> = (T, T, T);
+
+// three comma separated values like `1,0,1` or `19, 13, 30`
+pub fn vector3<'a, T>(
+    value: fn(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vector3<T>> {
+    let comma = || pair(char(','), space0);
+    tuple((
+        terminated(value, comma()),
+        terminated(value, comma()),
+        value,
+    ))
+}
+
+// two vectors with `separator` between them, like `1,0,1~1,2,1` or
+// `19, 13, 30 @ -2, 1, -2`
+pub fn vector3_pair<'a, T>(
+    value: fn(&'a str) -> IResult<&'a str, T>,
+    separator: char,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (Vector3<T>, Vector3<T>)> {
+    separated_pair(
+        vector3(value),
+        tuple((space0, char(separator), space0)),
+        vector3(value),
+    )
+}
+
+// one `line` per line
+pub fn lines<'a, T>(
+    line: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(line_ending, line)
+}
+
+// the end of a line followed by at least one empty line
+pub fn blank_lines(input: &str) -> IResult<&str, &str> {
+    recognize(pair(line_ending, many1(line_ending)))(input)
+}
+
+// sections separated by one or more blank lines
+pub fn blocks<'a, T>(
+    block: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(blank_lines, block)
+}
+
+// a grid of characters, each row on its own line
+pub fn char_grid(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    lines(many1(none_of("\r\n")))(input)
+}
+
+// Where and why parsing an input failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+    pub found: String,
+}
+
+impl ParseError {
+    fn at(input: &str, remaining: &str, kind: ErrorKind) -> Self {
+        let offset = input.len() - remaining.len();
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            kind,
+            found: not_line_ending::<&str, Error<&str>>(remaining)
+                .map(|(_, rest_of_line)| rest_of_line.to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "failed to parse line {} column {} ({:?}) at `{}`",
+            self.line, self.column, self.kind, self.found
+        ))
+    }
+}
+
+// runs `parser` over all of `input`, only trailing whitespace may be left over
+pub fn try_parse<'a, T>(
+    mut parser: impl Parser<&'a str, T, Error<&'a str>>,
+    input: &'a str,
+) -> Result<T, ParseError> {
+    let (remaining, parsed) = parser
+        .parse(input)
+        .finish()
+        .map_err(|err| ParseError::at(input, err.input, err.code))?;
+
+    if remaining.trim().is_empty() {
+        Ok(parsed)
+    } else {
+        Err(ParseError::at(input, remaining, ErrorKind::Eof))
+    }
+}
+
+// same as `try_parse`, but panics with the position it failed at
+pub fn parse<'a, T>(parser: impl Parser<&'a str, T, Error<&'a str>>, input: &'a str) -> T {
+    try_parse(parser, input).unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{character::complete::alpha1, error::ErrorKind};
+
+    use super::*;
+
+    #[test]
+    fn combinators() {
+        assert_eq!(
+            parse(key_values(unsigned::<u64>), "seeds: 79 14  55 13\n"),
+            ("seeds", vec![79, 14, 55, 13])
+        );
+        assert_eq!(
+            parse(
+                lines(vector3_pair(signed::<i64>, '@')),
+                "19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, -1, -2"
+            ),
+            vec![((19, 13, 30), (-2, 1, -2)), ((18, 19, 22), (-1, -1, -2))]
+        );
+        assert_eq!(
+            parse(blocks(char_grid), "#.\n.#\n\n\n##\n"),
+            vec![vec![vec!['#', '.'], vec!['.', '#']], vec![vec!['#', '#']]]
+        );
+    }
+
+    #[test]
+    fn reports_position() {
+        let err = try_parse(lines(key_values(alpha1)), "jqt: rhn xhk\nrsh: frs 42").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                line: 2,
+                column: 9,
+                kind: ErrorKind::Eof,
+                found: " 42".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "failed to parse line 2 column 9 (Eof) at ` 42`"
+        );
+    }
+}
@@ -0,0 +1,371 @@
+use std::{
+    io::{BufRead, BufReader, ErrorKind, Read, Write},
+    net::{TcpListener, TcpStream},
+    panic::{self, AssertUnwindSafe},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::solutions;
+
+// the largest body a request can have, anything larger is refused before it's
+// read so a client can't make the server allocate as much as it claims
+pub const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+// how long a client can go without sending or reading anything before the
+// connection is given up on, so a stalled client doesn't hold a thread forever
+#[cfg(not(test))]
+const TIMEOUT: Duration = Duration::from_secs(10);
+#[cfg(test)]
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+// A small HTTP/JSON front end over the registered solutions.
+//
+//     GET  /solutions                  lists every registered year, day and part
+//     POST /solve/{year}/{day}/{part}  solves the puzzle input sent as the body
+//     POST /solve/{day}/{part}         the same for the latest year
+pub fn serve(port: u16) {
+    let listener = TcpListener::bind(("127.0.0.1", port)).expect("Failed to bind to port");
+    println!("Listening on http://{}", listener.local_addr().unwrap());
+    serve_listener(listener);
+}
+
+pub fn serve_listener(listener: TcpListener) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                thread::spawn(move || handle_connection(stream));
+            }
+            Err(err) => eprintln!("Failed to accept connection: {err}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+#[derive(Debug)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+fn handle_connection(mut stream: TcpStream) {
+    if let Err(err) = stream
+        .set_read_timeout(Some(TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)))
+    {
+        eprintln!("Failed to set connection timeouts: {err}");
+        return;
+    }
+
+    let response = match read_request(&mut stream) {
+        Ok(request) => route(request),
+        Err(response) => response,
+    };
+
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error",
+    };
+
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        response.body.len(),
+        response.body
+    );
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<Request, Response> {
+    let bad_request = |message: String| error_response(400, &message);
+    let read_error = |err: std::io::Error| match err.kind() {
+        ErrorKind::WouldBlock | ErrorKind::TimedOut => {
+            bad_request("Timed out reading the request".to_string())
+        }
+        _ => bad_request(err.to_string()),
+    };
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).map_err(read_error)?;
+
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(bad_request(format!(
+            "Malformed request line `{}`",
+            request_line.trim_end()
+        )));
+    };
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).map_err(read_error)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().map_err(|_| {
+                    bad_request(format!("Invalid Content-Length `{}`", value.trim()))
+                })?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(error_response(
+            413,
+            &format!(
+                "Body of {} bytes is larger than the limit of {} bytes",
+                content_length, MAX_BODY_BYTES
+            ),
+        ));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(read_error)?;
+
+    Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        body: String::from_utf8(body)
+            .map_err(|_| bad_request("Body is not valid UTF-8".to_string()))?,
+    })
+}
+
+fn route(request: Request) -> Response {
+    let segments = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .collect::<Vec<_>>();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["solutions"]) => list_solutions(),
+        ("POST", ["solve", year, day, part]) => match (year.parse(), day.parse(), part.parse()) {
+            (Ok(year), Ok(day), Ok(part)) => solve(year, day, part, request.body),
+            _ => error_response(
+                400,
+                &format!("Invalid year `{year}`, day `{day}` or part `{part}`"),
+            ),
+        },
+        ("POST", ["solve", day, part]) => match (day.parse(), part.parse()) {
+            (Ok(day), Ok(part)) => solve(solutions::latest_year(), day, part, request.body),
+            _ => error_response(400, &format!("Invalid day `{day}` or part `{part}`")),
+        },
+        (_, ["solutions"]) | (_, ["solve", _, _]) | (_, ["solve", _, _, _]) => {
+            error_response(405, "Method not allowed")
+        }
+        _ => error_response(404, &format!("No route for `{}`", request.path)),
+    }
+}
+
+fn list_solutions() -> Response {
+    let solutions = solutions::registered()
+        .map(|(year, day, part)| format!("{{\"year\":{},\"day\":{},\"part\":{}}}", year, day, part))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    Response {
+        status: 200,
+        body: format!("{{\"solutions\":[{}]}}", solutions),
+    }
+}
+
+fn solve(year: u32, day: u32, part: u32, input: String) -> Response {
+    let Some(solver) = solutions::solver(year, day, part) else {
+        return error_response(
+            404,
+            &format!("No solution for {year} day {day} part {part}"),
+        );
+    };
+
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solver(input)));
+    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    match result {
+        Ok(answer) => Response {
+            status: 200,
+            body: format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ms\":{:.3}}}",
+                year,
+                day,
+                part,
+                json_string(&answer),
+                elapsed_ms
+            ),
+        },
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<String>()
+                .map(|s| s.as_str())
+                .or_else(|| panic.downcast_ref::<&str>().copied())
+                .unwrap_or("solution panicked");
+            Response {
+                status: 422,
+                body: format!(
+                    "{{\"year\":{},\"day\":{},\"part\":{},\"error\":{},\"elapsed_ms\":{:.3}}}",
+                    year,
+                    day,
+                    part,
+                    json_string(message),
+                    elapsed_ms
+                ),
+            }
+        }
+    }
+}
+
+fn error_response(status: u16, message: &str) -> Response {
+    Response {
+        status,
+        body: format!("{{\"error\":{}}}", json_string(message)),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::{SocketAddr, TcpListener, TcpStream},
+        thread,
+    };
+
+    fn start_server() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || super::serve_listener(listener));
+        addr
+    }
+
+    fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn solve_sample() {
+        let addr = start_server();
+        let input = std::fs::read_to_string("inputs/2023/day_01/part_1/sample.txt").unwrap();
+
+        let response = request(addr, "POST", "/solve/2023/1/1", &input);
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("\"answer\":\"142\""));
+        assert!(response.contains("\"elapsed_ms\":"));
+    }
+
+    #[test]
+    fn solve_latest_year() {
+        let addr = start_server();
+        let input = std::fs::read_to_string("inputs/2023/day_01/part_1/sample.txt").unwrap();
+
+        let response = request(addr, "POST", "/solve/1/1", &input);
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("\"year\":2023"));
+        assert!(response.contains("\"answer\":\"142\""));
+
+        let response = request(addr, "GET", "/solve/1/1", "");
+        assert!(response.starts_with("HTTP/1.1 405"));
+    }
+
+    #[test]
+    fn list_and_errors() {
+        let addr = start_server();
+
+        let response = request(addr, "GET", "/solutions", "");
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("{\"year\":2023,\"day\":25,\"part\":2}"));
+
+        let response = request(addr, "POST", "/solve/2023/26/1", "");
+        assert!(response.starts_with("HTTP/1.1 404"));
+
+        let response = request(addr, "POST", "/solve/2023/18/1", "X 1 (#000000)");
+        assert!(response.starts_with("HTTP/1.1 422"));
+        assert!(response.contains("Unexpected direction X"));
+    }
+
+    #[test]
+    fn refuses_large_bodies() {
+        let addr = start_server();
+
+        for (content_length, status) in [("99999999999", "413"), ("lots", "400")] {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            write!(
+                stream,
+                "POST /solve/2023/1/1 HTTP/1.1\r\nContent-Length: {content_length}\r\n\r\n"
+            )
+            .unwrap();
+
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            assert!(response.starts_with(&format!("HTTP/1.1 {status}")));
+        }
+    }
+
+    #[test]
+    fn times_out_stalled_requests() {
+        let addr = start_server();
+
+        // a request that stops halfway through its body
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST /solve/2023/1/1 HTTP/1.1\r\nContent-Length: 10\r\n\r\n1abc"
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 400"));
+        assert!(response.contains("Timed out"));
+    }
+
+    #[test]
+    fn escapes_json() {
+        assert_eq!(super::json_string("a\"b\\c\nd"), "\"a\\\"b\\\\c\\nd\"");
+    }
+}
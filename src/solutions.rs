@@ -0,0 +1,87 @@
+use std::io::BufRead;
+
+use crate::explain::Explain;
+use crate::validate::Problem;
+use crate::y2023;
+
+// Every solution is registered here so that the runner, and anything else
+// that needs to call a solution by its year, day and part, goes through one
+// place. Each year keeps its own table of solutions in its module.
+pub type Solver = fn(String) -> String;
+pub type Explainer = fn(String, &mut dyn Explain) -> String;
+pub type Validator = fn(&str) -> Vec<Problem>;
+pub type StreamingSolver = fn(Box<dyn BufRead>) -> String;
+
+// every year with solutions, the last one is run when no year is given
+pub const YEARS: [u32; 1] = [2023];
+
+pub fn latest_year() -> u32 {
+    *YEARS.last().unwrap()
+}
+
+pub fn solver(year: u32, day: u32, part: u32) -> Option<Solver> {
+    match year {
+        2023 => y2023::solver(day, part),
+        _ => None,
+    }
+}
+
+// same as `solver` but for the parts that can explain their answer
+pub fn explainer(year: u32, day: u32, part: u32) -> Option<Explainer> {
+    match year {
+        2023 => y2023::explainer(day, part),
+        _ => None,
+    }
+}
+
+// same as `solver` but for the parts that can read their input a line at a
+// time, for inputs too large to read into memory
+pub fn streaming_solver(year: u32, day: u32, part: u32) -> Option<StreamingSolver> {
+    match year {
+        2023 => y2023::streaming_solver(day, part),
+        _ => None,
+    }
+}
+
+// checks an input for a day is well formed, shared by both parts
+pub fn validator(year: u32, day: u32) -> Option<Validator> {
+    match year {
+        2023 => y2023::validator(day),
+        _ => None,
+    }
+}
+
+// the source of each day, used to tell when a cached answer was computed by
+// an older version of the solution
+pub fn source(year: u32, day: u32) -> Option<&'static str> {
+    match year {
+        2023 => y2023::source(day),
+        _ => None,
+    }
+}
+
+// the modules that solutions share, a change to any of them can change an
+// answer as much as a change to the day itself
+const SHARED_SOURCES: [&str; 3] = [
+    include_str!("parsing.rs"),
+    include_str!("streaming.rs"),
+    include_str!("explain.rs"),
+];
+
+// the source of a day along with the shared modules, empty if there's no such
+// day
+pub fn version(year: u32, day: u32) -> String {
+    let Some(source) = source(year, day) else {
+        return String::new();
+    };
+    std::iter::once(source).chain(SHARED_SOURCES).collect()
+}
+
+// all the (year, day, part) triples that have a solution
+pub fn registered() -> impl Iterator<Item = (u32, u32, u32)> {
+    YEARS
+        .into_iter()
+        .flat_map(|year| (1..=25).map(move |day| (year, day)))
+        .flat_map(|(year, day)| (1..=2).map(move |part| (year, day, part)))
+        .filter(|(year, day, part)| solver(*year, *day, *part).is_some())
+}
@@ -0,0 +1,50 @@
+use std::io::BufRead;
+
+// Inputs larger than this are read a piece at a time by the days that have a
+// streaming solution, instead of being read into memory up front.
+pub const THRESHOLD_BYTES: u64 = 64 * 1024 * 1024;
+
+// the lines of `input`, panicking if it can't be read
+pub fn lines(input: impl BufRead) -> impl Iterator<Item = String> {
+    input.lines().map(|line| line.expect("Failed to read line"))
+}
+
+// The pieces of `input` between each `separator` the same way as
+// `str::split_terminator`, empty pieces included. The line ending at the end
+// of the input is left out, and isn't a piece of its own after a last
+// separator.
+pub fn split(input: impl BufRead, separator: u8) -> impl Iterator<Item = String> {
+    let mut pieces = input
+        .split(separator)
+        .map(|piece| {
+            String::from_utf8(piece.expect("Failed to read input"))
+                .expect("Input is not valid UTF-8")
+        })
+        .peekable();
+
+    std::iter::from_fn(move || {
+        let piece = pieces.next()?;
+        let trimmed = piece.trim_end_matches(['\r', '\n']);
+        if trimmed.is_empty() && !piece.is_empty() && pieces.peek().is_none() {
+            return None;
+        }
+        Some(trimmed.to_string())
+    })
+}
+
+#[test]
+fn split_pieces() {
+    let pieces = split("rn=1,cm-,,qp=3\n".as_bytes(), b',').collect::<Vec<_>>();
+    assert_eq!(pieces, vec!["rn=1", "cm-", "", "qp=3"]);
+    let pieces = split("rn=1,\n".as_bytes(), b',').collect::<Vec<_>>();
+    assert_eq!(pieces, vec!["rn=1"]);
+
+    for input in ["", ",", ",,", "a,,b", ",a,b,", "a,b,,"] {
+        assert_eq!(
+            split(input.as_bytes(), b',').collect::<Vec<_>>(),
+            input.split_terminator(',').collect::<Vec<_>>(),
+            "splitting `{}`",
+            input
+        );
+    }
+}
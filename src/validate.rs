@@ -0,0 +1,207 @@
+use std::{fmt::Display, str::FromStr};
+
+// Everything wrong with an input, found before trying to solve it.
+// `line` is 1-based, and is `None` for problems with the input as a whole.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => f.write_fmt(format_args!("line {}: {}", line, self.message)),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Problems(Vec<Problem>);
+
+impl Problems {
+    pub fn at(&mut self, line: usize, message: impl Into<String>) {
+        self.0.push(Problem {
+            line: Some(line),
+            message: message.into(),
+        });
+    }
+
+    pub fn input(&mut self, message: impl Into<String>) {
+        self.0.push(Problem {
+            line: None,
+            message: message.into(),
+        });
+    }
+
+    // parses `s`, recording a problem if it isn't a number
+    pub fn number<T: FromStr>(&mut self, line: usize, s: &str) -> Option<T> {
+        let number = s.trim().parse().ok();
+        if number.is_none() {
+            self.at(line, format!("expected a number, found `{}`", s.trim()));
+        }
+        number
+    }
+
+    // parses every whitespace separated number in `s`
+    pub fn numbers<T: FromStr>(&mut self, line: usize, s: &str) -> Option<Vec<T>> {
+        s.split_whitespace()
+            .map(|n| self.number(line, n))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    // checks that `line` is `prefix` followed by the rest, and returns the rest
+    pub fn prefixed<'a>(&mut self, line: usize, s: &'a str, prefix: &str) -> Option<&'a str> {
+        let rest = s.strip_prefix(prefix);
+        if rest.is_none() {
+            self.at(line, format!("expected line to start with `{}`", prefix));
+        }
+        rest
+    }
+
+    pub fn into_vec(self) -> Vec<Problem> {
+        self.0
+    }
+}
+
+// the lines of the input along with their line number
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+// groups of lines separated by blank lines, keeping their line numbers
+pub fn blocks(input: &str) -> Vec<Vec<(usize, &str)>> {
+    let mut blocks = vec![vec![]];
+    for (n, line) in lines(input) {
+        if line.is_empty() {
+            blocks.push(vec![]);
+        } else {
+            blocks.last_mut().unwrap().push((n, line));
+        }
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+pub fn not_empty(problems: &mut Problems, input: &str) -> bool {
+    let empty = input.trim().is_empty();
+    if empty {
+        problems.input("input is empty");
+    }
+    !empty
+}
+
+// checks the lines form a rectangle made only of the `allowed` characters
+pub fn grid(problems: &mut Problems, lines: &[(usize, &str)], allowed: &str) {
+    let Some((_, first)) = lines.first() else {
+        problems.input("expected a grid, found nothing");
+        return;
+    };
+    let width = first.chars().count();
+
+    for (n, line) in lines {
+        let line_width = line.chars().count();
+        if line_width != width {
+            problems.at(
+                *n,
+                format!(
+                    "grid is not rectangular, row is {} wide but the first row is {} wide",
+                    line_width, width
+                ),
+            );
+        }
+        if let Some((col, c)) = line
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !allowed.contains(*c))
+        {
+            problems.at(
+                *n,
+                format!(
+                    "unexpected `{}` in column {}, expected one of `{}`",
+                    c,
+                    col + 1,
+                    allowed
+                ),
+            );
+        }
+    }
+}
+
+// checks `c` appears exactly once in the input
+pub fn exactly_one(problems: &mut Problems, input: &str, c: char) {
+    let found = lines(input)
+        .filter(|(_, line)| line.contains(c))
+        .map(|(n, _)| n)
+        .collect::<Vec<_>>();
+    let count = input.matches(c).count();
+
+    match count {
+        0 => problems.input(format!("expected one `{}`, found none", c)),
+        1 => {}
+        _ => {
+            for n in found {
+                problems.at(
+                    n,
+                    format!("expected one `{}`, found {} in the input", c, count),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solutions;
+
+    fn messages(day: u32, input: &str) -> Vec<String> {
+        solutions::validator(2023, day).unwrap()(input)
+            .iter()
+            .map(|problem| problem.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn samples_are_valid() {
+        for (year, day, part) in solutions::registered() {
+            let path = format!("inputs/{}/day_{:02}/part_{}/sample.txt", year, day, part);
+            // day 25 has no second part, so no sample for it
+            let input = std::fs::read_to_string(&path).unwrap_or_default();
+            if input.is_empty() {
+                continue;
+            }
+            let problems = solutions::validator(year, day).unwrap()(&input);
+            assert!(problems.is_empty(), "{}: {:?}", path, problems);
+        }
+    }
+
+    #[test]
+    fn reports_line_numbers() {
+        assert_eq!(
+            messages(10, ".S.\n.S.\n..\n"),
+            vec![
+                "line 3: grid is not rectangular, row is 2 wide but the first row is 3 wide",
+                "line 1: expected one `S`, found 2 in the input",
+                "line 2: expected one `S`, found 2 in the input",
+            ]
+        );
+        assert_eq!(
+            messages(20, "%a -> b\n&b -> a\n"),
+            vec!["expected one `broadcaster` module, found none"]
+        );
+        assert_eq!(
+            messages(
+                19,
+                "px{a<2006:qkq,m>2090:A,rfg}\n\n{x=787,m=2655,a=1222,s=2876}\n"
+            ),
+            vec![
+                "line 1: workflow `px` sends parts to `qkq` which doesn't exist",
+                "line 1: workflow `px` sends parts to `rfg` which doesn't exist",
+                "there is no `in` workflow to start from",
+            ]
+        );
+    }
+}
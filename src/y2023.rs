@@ -0,0 +1,188 @@
+use crate::solutions::{Explainer, Solver, StreamingSolver, Validator};
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+
+// the tables `solutions` looks up when asked for a 2023 puzzle
+pub fn solver(day: u32, part: u32) -> Option<Solver> {
+    let solver: Solver = match (day, part) {
+        (1, 1) => |input| day_01::part_1(input).to_string(),
+        (1, 2) => |input| day_01::part_2(input).to_string(),
+        (2, 1) => |input| day_02::part_1(input).to_string(),
+        (2, 2) => |input| day_02::part_2(input).to_string(),
+        (3, 1) => |input| day_03::part_1(input).to_string(),
+        (3, 2) => |input| day_03::part_2(input).to_string(),
+        (4, 1) => |input| day_04::part_1(input).to_string(),
+        (4, 2) => |input| day_04::part_2(input).to_string(),
+        (5, 1) => |input| day_05::part_1(input).to_string(),
+        (5, 2) => |input| day_05::part_2(input).to_string(),
+        (6, 1) => |input| day_06::part_1(input).to_string(),
+        (6, 2) => |input| day_06::part_2(input).to_string(),
+        (7, 1) => |input| day_07::part_1(input).to_string(),
+        (7, 2) => |input| day_07::part_2(input).to_string(),
+        (8, 1) => |input| day_08::part_1(input).to_string(),
+        (8, 2) => |input| day_08::part_2(input).to_string(),
+        (9, 1) => |input| day_09::part_1(input).to_string(),
+        (9, 2) => |input| day_09::part_2(input).to_string(),
+        (10, 1) => |input| day_10::part_1(input).to_string(),
+        (10, 2) => |input| day_10::part_2(input).to_string(),
+        (11, 1) => |input| day_11::part_1(input).to_string(),
+        (11, 2) => |input| day_11::part_2(input).to_string(),
+        (12, 1) => |input| day_12::part_1(input).to_string(),
+        (12, 2) => |input| day_12::part_2(input).to_string(),
+        (13, 1) => |input| day_13::part_1(input).to_string(),
+        (13, 2) => |input| day_13::part_2(input).to_string(),
+        (14, 1) => |input| day_14::part_1(input).to_string(),
+        (14, 2) => |input| day_14::part_2(input).to_string(),
+        (15, 1) => |input| day_15::part_1(input).to_string(),
+        (15, 2) => |input| day_15::part_2(input).to_string(),
+        (16, 1) => |input| day_16::part_1(input).to_string(),
+        (16, 2) => |input| day_16::part_2(input).to_string(),
+        (17, 1) => |input| day_17::part_1(input).to_string(),
+        (17, 2) => |input| day_17::part_2(input).to_string(),
+        (18, 1) => |input| day_18::part_1(input).to_string(),
+        (18, 2) => |input| day_18::part_2(input).to_string(),
+        (19, 1) => |input| day_19::part_1(input).to_string(),
+        (19, 2) => |input| day_19::part_2(input).to_string(),
+        (20, 1) => |input| day_20::part_1(input).to_string(),
+        (20, 2) => |input| day_20::part_2(input).to_string(),
+        (21, 1) => |input| day_21::part_1(input).to_string(),
+        (21, 2) => |input| day_21::part_2(input).to_string(),
+        (22, 1) => |input| day_22::part_1(input).to_string(),
+        (22, 2) => |input| day_22::part_2(input).to_string(),
+        (23, 1) => |input| day_23::part_1(input).to_string(),
+        (23, 2) => |input| day_23::part_2(input).to_string(),
+        (24, 1) => |input| day_24::part_1(input).to_string(),
+        (24, 2) => |input| day_24::part_2(input).to_string(),
+        (25, 1) => |input| day_25::part_1(input).to_string(),
+        (25, 2) => |input| day_25::part_2(input).to_string(),
+        _ => return None,
+    };
+
+    Some(solver)
+}
+
+pub fn explainer(day: u32, part: u32) -> Option<Explainer> {
+    let explainer: Explainer = match (day, part) {
+        (4, 1) => |input, sink| day_04::part_1_explained(input, sink).to_string(),
+        (4, 2) => |input, sink| day_04::part_2_explained(input, sink).to_string(),
+        (5, 1) => |input, sink| day_05::part_1_explained(input, sink).to_string(),
+        (5, 2) => |input, sink| day_05::part_2_explained(input, sink).to_string(),
+        (7, 1) => |input, sink| day_07::part_1_explained(input, sink).to_string(),
+        (7, 2) => |input, sink| day_07::part_2_explained(input, sink).to_string(),
+        (13, 1) => |input, sink| day_13::part_1_explained(input, sink).to_string(),
+        (13, 2) => |input, sink| day_13::part_2_explained(input, sink).to_string(),
+        (15, 1) => |input, sink| day_15::part_1_explained(input, sink).to_string(),
+        (15, 2) => |input, sink| day_15::part_2_explained(input, sink).to_string(),
+        _ => return None,
+    };
+
+    Some(explainer)
+}
+
+pub fn streaming_solver(day: u32, part: u32) -> Option<StreamingSolver> {
+    let streaming_solver: StreamingSolver = match (day, part) {
+        (1, 1) => |input| day_01::part_1_streaming(input).to_string(),
+        (1, 2) => |input| day_01::part_2_streaming(input).to_string(),
+        (2, 1) => |input| day_02::part_1_streaming(input).to_string(),
+        (2, 2) => |input| day_02::part_2_streaming(input).to_string(),
+        (4, 1) => |input| day_04::part_1_streaming(input).to_string(),
+        (4, 2) => |input| day_04::part_2_streaming(input).to_string(),
+        (9, 1) => |input| day_09::part_1_streaming(input).to_string(),
+        (9, 2) => |input| day_09::part_2_streaming(input).to_string(),
+        (15, 1) => |input| day_15::part_1_streaming(input).to_string(),
+        (15, 2) => |input| day_15::part_2_streaming(input).to_string(),
+        _ => return None,
+    };
+
+    Some(streaming_solver)
+}
+
+pub fn validator(day: u32) -> Option<Validator> {
+    let validator: Validator = match day {
+        1 => day_01::validate,
+        2 => day_02::validate,
+        3 => day_03::validate,
+        4 => day_04::validate,
+        5 => day_05::validate,
+        6 => day_06::validate,
+        7 => day_07::validate,
+        8 => day_08::validate,
+        9 => day_09::validate,
+        10 => day_10::validate,
+        11 => day_11::validate,
+        12 => day_12::validate,
+        13 => day_13::validate,
+        14 => day_14::validate,
+        15 => day_15::validate,
+        16 => day_16::validate,
+        17 => day_17::validate,
+        18 => day_18::validate,
+        19 => day_19::validate,
+        20 => day_20::validate,
+        21 => day_21::validate,
+        22 => day_22::validate,
+        23 => day_23::validate,
+        24 => day_24::validate,
+        25 => day_25::validate,
+        _ => return None,
+    };
+
+    Some(validator)
+}
+
+pub fn source(day: u32) -> Option<&'static str> {
+    let source = match day {
+        1 => include_str!("y2023/day_01.rs"),
+        2 => include_str!("y2023/day_02.rs"),
+        3 => include_str!("y2023/day_03.rs"),
+        4 => include_str!("y2023/day_04.rs"),
+        5 => include_str!("y2023/day_05.rs"),
+        6 => include_str!("y2023/day_06.rs"),
+        7 => include_str!("y2023/day_07.rs"),
+        8 => include_str!("y2023/day_08.rs"),
+        9 => include_str!("y2023/day_09.rs"),
+        10 => include_str!("y2023/day_10.rs"),
+        11 => include_str!("y2023/day_11.rs"),
+        12 => include_str!("y2023/day_12.rs"),
+        13 => include_str!("y2023/day_13.rs"),
+        14 => include_str!("y2023/day_14.rs"),
+        15 => include_str!("y2023/day_15.rs"),
+        16 => include_str!("y2023/day_16.rs"),
+        17 => include_str!("y2023/day_17.rs"),
+        18 => include_str!("y2023/day_18.rs"),
+        19 => include_str!("y2023/day_19.rs"),
+        20 => include_str!("y2023/day_20.rs"),
+        21 => include_str!("y2023/day_21.rs"),
+        22 => include_str!("y2023/day_22.rs"),
+        23 => include_str!("y2023/day_23.rs"),
+        24 => include_str!("y2023/day_24.rs"),
+        25 => include_str!("y2023/day_25.rs"),
+        _ => return None,
+    };
+
+    Some(source)
+}
@@ -0,0 +1,270 @@
+pub const DAY_STR: &str = "2023/day_01";
+
+pub use part_1::solution as part_1;
+pub use part_1::solution_streaming as part_1_streaming;
+pub use part_2::solution as part_2;
+pub use part_2::solution_streaming as part_2_streaming;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let digits = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    for (n, line) in validate::lines(input) {
+        if let Some(c) = line
+            .chars()
+            .find(|c| !c.is_ascii_lowercase() && !c.is_ascii_digit())
+        {
+            problems.at(
+                n,
+                format!("unexpected `{}`, expected letters and digits", c),
+            );
+        }
+        if !line.chars().any(|c| c.is_ascii_digit()) && !digits.iter().any(|d| line.contains(d)) {
+            problems.at(n, "line has no digits, spelled out or otherwise");
+        }
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+
+    use std::collections::{HashMap, VecDeque};
+    use std::io::BufRead;
+
+    use crate::streaming;
+
+    // Maps tokens found in a line to the value they stand for, like `7` or
+    // `seven` to 7. All the tokens are matched in one pass over each line with
+    // an Aho-Corasick automaton, so overlapping tokens like `twone` are all seen.
+    #[derive(Debug)]
+    pub struct Vocabulary {
+        // children of each state in the trie of tokens
+        next: Vec<HashMap<u8, usize>>,
+        // the longest proper suffix of each state that is also in the trie
+        fail: Vec<usize>,
+        // the (length, value) of every token ending at each state
+        found: Vec<Vec<(usize, u32)>>,
+    }
+
+    impl Vocabulary {
+        pub fn new<'a>(tokens: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+            let mut vocabulary = Vocabulary {
+                next: vec![HashMap::new()],
+                fail: vec![0],
+                found: vec![vec![]],
+            };
+
+            for (token, value) in tokens {
+                assert!(!token.is_empty(), "Tokens can't be empty");
+                let mut state = 0;
+                for byte in token.bytes() {
+                    state = match vocabulary.next[state].get(&byte) {
+                        Some(next) => *next,
+                        None => {
+                            vocabulary.next.push(HashMap::new());
+                            vocabulary.fail.push(0);
+                            vocabulary.found.push(vec![]);
+                            let next = vocabulary.next.len() - 1;
+                            vocabulary.next[state].insert(byte, next);
+                            next
+                        }
+                    };
+                }
+                vocabulary.found[state].push((token.len(), value));
+            }
+
+            // breadth first, so a state's fail link is done before its children
+            let mut queue = vocabulary.next[0]
+                .values()
+                .copied()
+                .collect::<VecDeque<_>>();
+            while let Some(state) = queue.pop_front() {
+                let children = vocabulary.next[state]
+                    .iter()
+                    .map(|(byte, child)| (*byte, *child))
+                    .collect::<Vec<_>>();
+                for (byte, child) in children {
+                    let fail = vocabulary.step(vocabulary.fail[state], byte);
+                    vocabulary.fail[child] = fail;
+                    let inherited = vocabulary.found[fail].clone();
+                    vocabulary.found[child].extend(inherited);
+                    queue.push_back(child);
+                }
+            }
+
+            vocabulary
+        }
+
+        // the digits, as in part 1
+        pub fn digits() -> Self {
+            Vocabulary::new((0..=9).map(|digit| (DIGITS[digit], digit as u32)))
+        }
+
+        // the digits and the spelled out digits, as in part 2
+        pub fn spelled_digits() -> Self {
+            Vocabulary::new(
+                (0..=9)
+                    .map(|digit| (DIGITS[digit], digit as u32))
+                    .chain((1..=9).map(|digit| (WORDS[digit - 1], digit as u32))),
+            )
+        }
+
+        fn step(&self, mut state: usize, byte: u8) -> usize {
+            loop {
+                if let Some(next) = self.next[state].get(&byte) {
+                    return *next;
+                }
+                if state == 0 {
+                    return 0;
+                }
+                state = self.fail[state];
+            }
+        }
+
+        // the values of the token that starts first and the token that ends last
+        // in `line`, when several start or end at the same place the longest wins
+        pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+            // (start, length, value)
+            let mut first: Option<(usize, usize, u32)> = None;
+            // (end, length, value)
+            let mut last: Option<(usize, usize, u32)> = None;
+
+            let mut state = 0;
+            for (end, byte) in line.bytes().enumerate() {
+                state = self.step(state, byte);
+                for &(len, value) in &self.found[state] {
+                    let start = end + 1 - len;
+                    if first.is_none_or(|(s, l, _)| start < s || (start == s && len > l)) {
+                        first = Some((start, len, value));
+                    }
+                    // tokens are found in the order they end
+                    if last.is_none_or(|(e, l, _)| end > e || len > l) {
+                        last = Some((end, len, value));
+                    }
+                }
+            }
+
+            Some((first?.2, last?.2))
+        }
+
+        // the first and last values written one after the other, so `1` and `2`
+        // give 12 and `1` and `10` give 110
+        pub fn calibration_value(&self, line: &str) -> u32 {
+            let (first, last) = self
+                .first_and_last(line)
+                .unwrap_or_else(|| panic!("No tokens found in `{}`", line));
+            first * 10_u32.pow(last.checked_ilog10().unwrap_or(0) + 1) + last
+        }
+    }
+
+    const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    pub fn solution(input: String) -> u32 {
+        let vocabulary = Vocabulary::digits();
+        input
+            .lines()
+            .map(|line| vocabulary.calibration_value(line))
+            .sum()
+    }
+
+    pub fn solution_streaming(input: impl BufRead) -> u32 {
+        let vocabulary = Vocabulary::digits();
+        streaming::lines(input)
+            .map(|line| vocabulary.calibration_value(&line))
+            .sum()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            142
+        );
+    }
+
+    #[test]
+    fn streaming_sample() {
+        let file =
+            std::fs::File::open(format!("inputs/{}/part_1/sample.txt", super::DAY_STR)).unwrap();
+        assert_eq!(solution_streaming(std::io::BufReader::new(file)), 142);
+    }
+
+    #[test]
+    fn other_vocabularies() {
+        let spelled = Vocabulary::spelled_digits();
+        assert_eq!(spelled.first_and_last("twone"), Some((2, 1)));
+        assert_eq!(spelled.first_and_last("eightwothree"), Some((8, 3)));
+        assert_eq!(spelled.first_and_last("abc"), None);
+
+        let roman = Vocabulary::new([
+            ("i", 1),
+            ("ii", 2),
+            ("iii", 3),
+            ("iv", 4),
+            ("v", 5),
+            ("ix", 9),
+            ("x", 10),
+        ]);
+        assert_eq!(roman.first_and_last("iiabix"), Some((2, 9)));
+        assert_eq!(roman.calibration_value("ivax"), 410);
+
+        let german = Vocabulary::new([("eins", 1), ("zwei", 2), ("drei", 3)]);
+        assert_eq!(german.calibration_value("zweinsdrei"), 23);
+    }
+}
+
+mod part_2 {
+
+    use std::io::BufRead;
+
+    use super::part_1::Vocabulary;
+
+    use crate::streaming;
+
+    pub fn solution(input: String) -> usize {
+        let vocabulary = Vocabulary::spelled_digits();
+        input
+            .lines()
+            .map(|line| vocabulary.calibration_value(line) as usize)
+            .sum()
+    }
+
+    pub fn solution_streaming(input: impl BufRead) -> usize {
+        let vocabulary = Vocabulary::spelled_digits();
+        streaming::lines(input)
+            .map(|line| vocabulary.calibration_value(&line) as usize)
+            .sum()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            281
+        );
+    }
+
+    #[test]
+    fn streaming_sample() {
+        let file =
+            std::fs::File::open(format!("inputs/{}/part_2/sample.txt", super::DAY_STR)).unwrap();
+        assert_eq!(solution_streaming(std::io::BufReader::new(file)), 281);
+    }
+}
@@ -0,0 +1,398 @@
+pub const DAY_STR: &str = "2023/day_02";
+
+pub use part_1::solution as part_1;
+pub use part_1::solution_streaming as part_1_streaming;
+pub use part_2::solution as part_2;
+pub use part_2::solution_streaming as part_2_streaming;
+
+pub use part_1::{colours, format_cubes, parse_bag, possible_games, read_games, smallest_bag};
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    for (n, line) in validate::lines(input) {
+        let Some(rest) = problems.prefixed(n, line, "Game ") else {
+            continue;
+        };
+        let Some((id, rounds)) = rest.split_once(": ") else {
+            problems.at(n, "expected `: ` after the game id");
+            continue;
+        };
+        problems.number::<u32>(n, id);
+        for cubes in rounds.split("; ").flat_map(|round| round.split(", ")) {
+            match cubes.split_once(' ') {
+                Some((count, colour))
+                    if !colour.is_empty() && colour.chars().all(|c| c.is_ascii_lowercase()) =>
+                {
+                    problems.number::<u32>(n, count);
+                }
+                _ => problems.at(
+                    n,
+                    format!(
+                        "expected a count and a colour like `3 blue`, found `{}`",
+                        cubes
+                    ),
+                ),
+            }
+        }
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::io::BufRead;
+
+    use itertools::Itertools;
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{alpha1, char},
+        multi::separated_list1,
+        sequence::{delimited, separated_pair},
+        IResult,
+    };
+
+    use crate::parsing::{self, lines, unsigned};
+    use crate::streaming;
+
+    // how many cubes of each colour, either shown in a round or in the bag
+    pub type Cubes = BTreeMap<String, u32>;
+
+    #[derive(Debug)]
+    pub struct Game {
+        pub id: u32,
+        pub rounds: Vec<Cubes>,
+    }
+
+    impl Game {
+        // whether every round could have been drawn from `bag`
+        pub fn is_possible(&self, bag: &Cubes) -> bool {
+            self.rounds.iter().all(|round| {
+                round
+                    .iter()
+                    .all(|(colour, count)| count <= bag.get(colour).unwrap_or(&0))
+            })
+        }
+
+        // the fewest cubes of each colour that make the game possible
+        pub fn minimum_bag(&self) -> Cubes {
+            let mut bag = Cubes::new();
+            for (colour, count) in self.rounds.iter().flatten() {
+                let needed = bag.entry(colour.clone()).or_default();
+                *needed = (*needed).max(*count);
+            }
+            bag
+        }
+
+        // the product of the minimum bag's counts of `colours`, usually every
+        // colour in the games, so a colour the game never shows counts as 0
+        pub fn power(&self, colours: &BTreeSet<String>) -> u64 {
+            let bag = self.minimum_bag();
+            colours
+                .iter()
+                .map(|colour| *bag.get(colour).unwrap_or(&0) as u64)
+                .product()
+        }
+    }
+
+    // a round like `3 blue, 4 red`, counts of the same colour are added up
+    fn parse_cubes(input: &str) -> IResult<&str, Cubes> {
+        let (input, cubes) = separated_list1(
+            tag(", "),
+            separated_pair(unsigned::<u32>, char(' '), alpha1),
+        )(input)?;
+
+        let mut round = Cubes::new();
+        for (count, colour) in cubes {
+            *round.entry(colour.to_string()).or_default() += count;
+        }
+        Ok((input, round))
+    }
+
+    pub fn parse_line(input: &str) -> IResult<&str, Game> {
+        let (input, id) = delimited(tag("Game "), unsigned, tag(": "))(input)?;
+
+        let (input, rounds) = separated_list1(tag("; "), parse_cubes)(input)?;
+        Ok((input, Game { id, rounds }))
+    }
+
+    pub fn parse_games(input: &str) -> IResult<&str, Vec<Game>> {
+        lines(parse_line)(input)
+    }
+
+    pub fn read_games(input: &str) -> Vec<Game> {
+        parsing::parse(parse_games, input)
+    }
+
+    // a bag written the same way as a round, like `12 red, 13 green, 14 blue`
+    pub fn parse_bag(input: &str) -> Cubes {
+        parsing::parse(parse_cubes, input)
+    }
+
+    pub fn format_cubes(cubes: &Cubes) -> String {
+        cubes
+            .iter()
+            .map(|(colour, count)| format!("{} {}", count, colour))
+            .join(", ")
+    }
+
+    // every colour shown in any of the games
+    pub fn colours(games: &[Game]) -> BTreeSet<String> {
+        games
+            .iter()
+            .flat_map(|game| game.rounds.iter().flat_map(|round| round.keys().cloned()))
+            .collect()
+    }
+
+    pub fn possible_games<'a>(
+        games: &'a [Game],
+        bag: &'a Cubes,
+    ) -> impl Iterator<Item = &'a Game> + 'a {
+        games.iter().filter(move |game| game.is_possible(bag))
+    }
+
+    // the bag with the fewest cubes in total that makes at least `at_least` of
+    // the games possible, or `None` if there aren't that many games
+    pub fn smallest_bag(games: &[Game], at_least: usize) -> Option<Cubes> {
+        if at_least > games.len() {
+            return None;
+        }
+
+        let minimums = games.iter().map(Game::minimum_bag).collect_vec();
+        let colours = colours(games);
+        let needed = minimums
+            .iter()
+            .map(|bag| {
+                colours
+                    .iter()
+                    .map(|colour| *bag.get(colour).unwrap_or(&0))
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let mut best = None;
+        search_bags(
+            &needed,
+            colours.len(),
+            at_least,
+            (0..games.len()).collect(),
+            &mut vec![],
+            &mut best,
+        );
+
+        let (_, counts) = best?;
+        Some(colours.into_iter().zip(counts).collect())
+    }
+
+    // picks a count for each colour in turn, only trying the counts some game
+    // needs, and keeps the games that still fit
+    fn search_bags(
+        needed: &[Vec<u32>],
+        colours: usize,
+        at_least: usize,
+        fitting: Vec<usize>,
+        counts: &mut Vec<u32>,
+        best: &mut Option<(u32, Vec<u32>)>,
+    ) {
+        let total = counts.iter().sum::<u32>();
+        let colour = counts.len();
+        if colour == colours {
+            if best
+                .as_ref()
+                .is_none_or(|(best_total, _)| total < *best_total)
+            {
+                *best = Some((total, counts.clone()));
+            }
+            return;
+        }
+
+        let options = fitting
+            .iter()
+            .map(|game| needed[*game][colour])
+            .chain([0])
+            .sorted()
+            .dedup();
+        for count in options {
+            if best
+                .as_ref()
+                .is_some_and(|(best_total, _)| total + count >= *best_total)
+            {
+                break;
+            }
+            let still_fitting = fitting
+                .iter()
+                .copied()
+                .filter(|game| needed[*game][colour] <= count)
+                .collect_vec();
+            if still_fitting.len() < at_least {
+                continue;
+            }
+
+            counts.push(count);
+            search_bags(needed, colours, at_least, still_fitting, counts, best);
+            counts.pop();
+        }
+    }
+
+    // the bag from the puzzle
+    pub fn puzzle_bag() -> Cubes {
+        parse_bag("12 red, 13 green, 14 blue")
+    }
+
+    pub fn solution(input: String) -> u32 {
+        let bag = puzzle_bag();
+        possible_games(&read_games(&input), &bag)
+            .map(|game| game.id)
+            .sum()
+    }
+
+    pub fn solution_streaming(input: impl BufRead) -> u32 {
+        let bag = puzzle_bag();
+        streaming::lines(input)
+            .map(|line| parsing::parse(parse_line, &line))
+            .filter(|game| game.is_possible(&bag))
+            .map(|game| game.id)
+            .sum()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            8
+        );
+    }
+
+    #[test]
+    fn streaming_sample() {
+        let file =
+            std::fs::File::open(format!("inputs/{}/part_1/sample.txt", super::DAY_STR)).unwrap();
+        assert_eq!(solution_streaming(std::io::BufReader::new(file)), 8);
+    }
+
+    #[test]
+    fn bag_queries() {
+        let games = read_games(
+            &std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                .unwrap(),
+        );
+
+        let bag = parse_bag("5 red, 3 green, 6 blue, 1 purple");
+        let possible = possible_games(&games, &bag)
+            .map(|game| game.id)
+            .collect_vec();
+        assert_eq!(possible, vec![1, 2]);
+
+        assert_eq!(
+            format_cubes(&games[2].minimum_bag()),
+            "6 blue, 13 green, 20 red"
+        );
+        assert_eq!(
+            smallest_bag(&games, 3).map(|bag| format_cubes(&bag)),
+            Some("6 blue, 3 green, 6 red".to_string())
+        );
+        assert_eq!(
+            smallest_bag(&games, 5).map(|bag| format_cubes(&bag)),
+            Some("15 blue, 13 green, 20 red".to_string())
+        );
+        assert_eq!(smallest_bag(&games, 6), None);
+
+        // colours other than red, green and blue work the same way
+        let games = read_games("Game 7: 2 teal, 1 red; 4 teal\nGame 8: 1 red");
+        assert_eq!(
+            possible_games(&games, &parse_bag("3 teal, 1 red"))
+                .map(|game| game.id)
+                .collect_vec(),
+            vec![8]
+        );
+        assert_eq!(games[0].power(&colours(&games)), 4);
+        assert_eq!(games[1].power(&colours(&games)), 0);
+        let games = read_games("Game 9: 1 red, 2 green; 3 blue, 4 teal\nGame 10: 2 red, 5 blue");
+        assert_eq!(games[0].power(&colours(&games)), 24);
+        assert_eq!(games[1].power(&colours(&games)), 0);
+        let games = read_games("Game 11: 3 teal; 1 teal");
+        assert_eq!(games[0].power(&colours(&games)), 3);
+    }
+}
+
+mod part_2 {
+
+    use std::collections::{BTreeSet, HashMap};
+    use std::io::BufRead;
+
+    use super::part_1::{colours, parse_line, read_games};
+
+    use crate::parsing;
+    use crate::streaming;
+
+    pub fn solution(input: String) -> u64 {
+        let games = read_games(&input);
+        let colours = colours(&games);
+        games.iter().map(|game| game.power(&colours)).sum()
+    }
+
+    pub fn solution_streaming(input: impl BufRead) -> u64 {
+        // a game only has a power if it shows every colour, so the powers
+        // are added up by the colours the games show until all of them are
+        // known
+        let mut by_colours = HashMap::<BTreeSet<String>, u64>::new();
+        for line in streaming::lines(input) {
+            let game = parsing::parse(parse_line, &line);
+            let shown = game.minimum_bag().into_keys().collect::<BTreeSet<_>>();
+            let power = game.power(&shown);
+            *by_colours.entry(shown).or_default() += power;
+        }
+
+        let all = by_colours
+            .keys()
+            .flatten()
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        by_colours.get(&all).copied().unwrap_or(0)
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            2286
+        );
+    }
+
+    #[test]
+    fn streaming_sample() {
+        let file =
+            std::fs::File::open(format!("inputs/{}/part_2/sample.txt", super::DAY_STR)).unwrap();
+        assert_eq!(solution_streaming(std::io::BufReader::new(file)), 2286);
+    }
+
+    #[test]
+    fn streaming_other_colours() {
+        for input in [
+            "Game 1: 2 teal, 1 red\nGame 2: 3 teal; 2 red\nGame 3: 1 teal",
+            "Game 1: 1 teal\nGame 2: 5 blue",
+        ] {
+            assert_eq!(
+                solution_streaming(input.as_bytes()),
+                solution(input.to_string())
+            );
+        }
+        assert_eq!(
+            solution_streaming("Game 1: 2 teal, 1 red\nGame 2: 3 teal; 2 red".as_bytes()),
+            8
+        );
+    }
+}
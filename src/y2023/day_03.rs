@@ -0,0 +1,232 @@
+pub const DAY_STR: &str = "2023/day_03";
+
+pub use part_1::solution as part_1;
+pub use part_2::solution as part_2;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let lines = validate::lines(input).collect::<Vec<_>>();
+    validate::grid(
+        &mut problems,
+        &lines,
+        "0123456789.!\"#$%&'()*+,-/:;<=>?@[\\]^_`{|}~",
+    );
+
+    problems.into_vec()
+}
+
+mod part_1 {
+
+    use std::collections::HashMap;
+
+    use itertools::Itertools;
+
+    // a number in the schematic, covering `start..end` of its row
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Number {
+        pub value: u32,
+        pub row: usize,
+        pub start: usize,
+        pub end: usize,
+    }
+
+    // anything other than a digit or a `.`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Symbol {
+        pub kind: char,
+        pub row: usize,
+        pub column: usize,
+    }
+
+    // Every number and symbol in the schematic, found in one pass, along with
+    // which of them are next to each other, including diagonally.
+    #[derive(Debug)]
+    pub struct Schematic {
+        pub numbers: Vec<Number>,
+        pub symbols: Vec<Symbol>,
+        // the indexes of the symbols next to each number
+        number_symbols: Vec<Vec<usize>>,
+        // the indexes of the numbers next to each symbol
+        symbol_numbers: Vec<Vec<usize>>,
+    }
+
+    impl Schematic {
+        pub fn new(input: &str) -> Self {
+            let mut numbers: Vec<Number> = vec![];
+            let mut symbols = vec![];
+            // which number each digit belongs to
+            let mut digits = HashMap::new();
+
+            for (row, line) in input.lines().enumerate() {
+                let mut current: Option<Number> = None;
+                for (column, c) in line.chars().chain(['.']).enumerate() {
+                    if let Some(digit) = c.to_digit(10) {
+                        let number = current.get_or_insert(Number {
+                            value: 0,
+                            row,
+                            start: column,
+                            end: column,
+                        });
+                        number.value = number.value * 10 + digit;
+                        number.end = column + 1;
+                        digits.insert((row, column), numbers.len());
+                        continue;
+                    }
+
+                    if let Some(number) = current.take() {
+                        numbers.push(number);
+                    }
+                    if c != '.' {
+                        symbols.push(Symbol {
+                            kind: c,
+                            row,
+                            column,
+                        });
+                    }
+                }
+            }
+
+            let mut number_symbols = vec![vec![]; numbers.len()];
+            let symbol_numbers = symbols
+                .iter()
+                .enumerate()
+                .map(|(index, symbol)| {
+                    let next_to = (symbol.row.saturating_sub(1)..=symbol.row + 1)
+                        .cartesian_product(symbol.column.saturating_sub(1)..=symbol.column + 1)
+                        .filter_map(|position| digits.get(&position).copied())
+                        .sorted()
+                        .dedup()
+                        .collect_vec();
+                    for number in &next_to {
+                        number_symbols[*number].push(index);
+                    }
+                    next_to
+                })
+                .collect_vec();
+
+            Schematic {
+                numbers,
+                symbols,
+                number_symbols,
+                symbol_numbers,
+            }
+        }
+
+        // numbers next to at least one symbol
+        pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+            self.numbers
+                .iter()
+                .zip(&self.number_symbols)
+                .filter(|(_, symbols)| !symbols.is_empty())
+                .map(|(number, _)| number)
+        }
+
+        // numbers that aren't next to any symbol
+        pub fn unattached_numbers(&self) -> impl Iterator<Item = &Number> {
+            self.numbers
+                .iter()
+                .zip(&self.number_symbols)
+                .filter(|(_, symbols)| symbols.is_empty())
+                .map(|(number, _)| number)
+        }
+
+        // numbers next to at least one symbol of `kind`
+        pub fn numbers_next_to(&self, kind: char) -> impl Iterator<Item = &Number> + '_ {
+            self.numbers
+                .iter()
+                .zip(&self.number_symbols)
+                .filter(move |(_, symbols)| symbols.iter().any(|s| self.symbols[*s].kind == kind))
+                .map(|(number, _)| number)
+        }
+
+        // symbols of `kind` with exactly `count` numbers next to them, along with
+        // those numbers
+        pub fn symbols_with(
+            &self,
+            kind: char,
+            count: usize,
+        ) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+            self.symbols
+                .iter()
+                .zip(&self.symbol_numbers)
+                .filter(move |(symbol, numbers)| symbol.kind == kind && numbers.len() == count)
+                .map(|(symbol, numbers)| {
+                    (
+                        symbol,
+                        numbers.iter().map(|n| &self.numbers[*n]).collect_vec(),
+                    )
+                })
+        }
+
+        // the product of the numbers next to each symbol from `symbols_with`
+        pub fn products(&self, kind: char, count: usize) -> impl Iterator<Item = u64> + '_ {
+            self.symbols_with(kind, count)
+                .map(|(_, numbers)| numbers.iter().map(|number| number.value as u64).product())
+        }
+    }
+
+    pub fn solution(input: String) -> u32 {
+        Schematic::new(&input)
+            .part_numbers()
+            .map(|number| number.value)
+            .sum()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            4361
+        );
+    }
+
+    #[test]
+    fn queries() {
+        let schematic = Schematic::new(
+            &std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                .unwrap(),
+        );
+        let values =
+            |numbers: Vec<&Number>| numbers.iter().map(|number| number.value).collect_vec();
+
+        assert_eq!(
+            values(schematic.unattached_numbers().collect()),
+            vec![114, 58]
+        );
+        assert_eq!(values(schematic.numbers_next_to('#').collect()), vec![633]);
+        assert_eq!(schematic.products('*', 1).collect_vec(), vec![617]);
+
+        let (symbol, numbers) = schematic.symbols_with('*', 2).next().unwrap();
+        assert_eq!((symbol.row, symbol.column), (1, 3));
+        assert_eq!(values(numbers), vec![467, 35]);
+    }
+}
+
+mod part_2 {
+
+    use super::part_1::Schematic;
+
+    pub fn solution(input: String) -> u64 {
+        Schematic::new(&input).products('*', 2).sum()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            467835
+        );
+    }
+}
@@ -0,0 +1,349 @@
+pub const DAY_STR: &str = "2023/day_04";
+
+pub use part_1::solution as part_1;
+pub use part_1::solution_explained as part_1_explained;
+pub use part_1::solution_streaming as part_1_streaming;
+pub use part_2::solution as part_2;
+pub use part_2::solution_explained as part_2_explained;
+pub use part_2::solution_streaming as part_2_streaming;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    for (n, line) in validate::lines(input) {
+        let Some(rest) = problems.prefixed(n, line, "Card ") else {
+            continue;
+        };
+        let Some((id, numbers)) = rest.split_once(':') else {
+            problems.at(n, "expected `:` after the card id");
+            continue;
+        };
+        // part 2 finds the cards won by their position
+        if problems.number::<usize>(n, id).is_some_and(|id| id != n) {
+            problems.at(
+                n,
+                format!("expected card {}, cards should be numbered in order", n),
+            );
+        }
+        match numbers.split_once('|') {
+            Some((winning, have)) => {
+                problems.numbers::<u32>(n, winning);
+                problems.numbers::<u32>(n, have);
+            }
+            None => problems.at(
+                n,
+                "expected `|` between the winning numbers and your numbers",
+            ),
+        }
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+    use std::{collections::HashSet, io::BufRead};
+
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{char, space1},
+        sequence::{delimited, pair, separated_pair, tuple},
+        IResult,
+    };
+
+    use crate::explain;
+    use crate::explain::{Explain, Silent};
+    use crate::parsing::{self, lines, unsigned, unsigned_list};
+    use crate::streaming;
+
+    #[derive(Debug)]
+    pub struct Game {
+        pub id: u32,
+        pub winning: HashSet<u32>,
+        pub given: HashSet<u32>,
+    }
+
+    pub fn parse_line(input: &str) -> IResult<&str, Game> {
+        let (input, game_id) =
+            delimited(pair(tag("Card"), space1), unsigned, pair(char(':'), space1))(input)?;
+        let (input, (winning_numbers, given_numbers)) = separated_pair(
+            unsigned_list,
+            tuple((space1, char('|'), space1)),
+            unsigned_list,
+        )(input)?;
+
+        Ok((
+            input,
+            Game {
+                id: game_id,
+                winning: HashSet::from_iter(winning_numbers),
+                given: HashSet::from_iter(given_numbers),
+            },
+        ))
+    }
+
+    pub fn parse_games(input: &str) -> IResult<&str, Vec<Game>> {
+        lines(parse_line)(input)
+    }
+
+    pub fn solution(input: String) -> usize {
+        solution_explained(input, &mut Silent)
+    }
+
+    impl Game {
+        pub fn matches(&self) -> usize {
+            self.winning.intersection(&self.given).count()
+        }
+    }
+
+    fn points(game: Game, explain: &mut dyn Explain) -> usize {
+        let matches = game.matches();
+        let points = match matches {
+            0 => 0,
+            x => 2_usize.pow((x as u32) - 1),
+        };
+        explain!(
+            explain,
+            "Card {}: {} matches, worth {} points",
+            game.id,
+            matches,
+            points
+        );
+        points
+    }
+
+    pub fn solution_explained(input: String, explain: &mut dyn Explain) -> usize {
+        parsing::parse(parse_games, &input)
+            .into_iter()
+            .map(|game| points(game, explain))
+            .sum::<usize>()
+    }
+
+    pub fn solution_streaming(input: impl BufRead) -> usize {
+        streaming::lines(input)
+            .map(|line| points(parsing::parse(parse_line, &line), &mut Silent))
+            .sum::<usize>()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            13
+        );
+    }
+
+    #[test]
+    fn streaming_sample() {
+        let file =
+            std::fs::File::open(format!("inputs/{}/part_1/sample.txt", super::DAY_STR)).unwrap();
+        assert_eq!(solution_streaming(std::io::BufReader::new(file)), 13);
+    }
+}
+
+mod part_2 {
+
+    use std::collections::VecDeque;
+    use std::io::BufRead;
+
+    use super::part_1::{parse_games, parse_line, Game};
+
+    use crate::explain;
+    use crate::explain::{Explain, Silent};
+    use crate::parsing;
+    use crate::streaming;
+
+    // how many copies of a card were won, and how many matches it has
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CardCount {
+        pub id: u32,
+        pub matches: usize,
+        pub copies: u128,
+    }
+
+    #[derive(Debug)]
+    pub struct Cascade {
+        pub cards: Vec<CardCount>,
+        // cards whose matches win copies past the end of the table, those copies
+        // don't exist so they're left out
+        pub past_the_end: Vec<u32>,
+    }
+
+    impl Cascade {
+        pub fn total(&self) -> u128 {
+            self.cards
+                .iter()
+                .try_fold(0_u128, |total, card| total.checked_add(card.copies))
+                .expect("Total number of cards overflowed u128")
+        }
+    }
+
+    // works out how many copies of each card are won, each card wins copies of
+    // the `matches` cards after it
+    pub fn cascade(games: &[Game], explain: &mut dyn Explain) -> Cascade {
+        let mut cards = games
+            .iter()
+            .map(|game| CardCount {
+                id: game.id,
+                matches: game.matches(),
+                copies: 1,
+            })
+            .collect::<Vec<_>>();
+        let mut past_the_end = vec![];
+
+        for i in 0..cards.len() {
+            let CardCount {
+                id,
+                matches,
+                copies,
+            } = cards[i];
+
+            let won = (i + 1)..(i + 1 + matches).min(cards.len());
+            if matches == 0 {
+                explain!(explain, "Card {} (x{}): no matches", id, copies);
+            } else if won.is_empty() {
+                explain!(
+                    explain,
+                    "Card {} (x{}): {} matches, but it's the last card",
+                    id,
+                    copies,
+                    matches
+                );
+            } else {
+                explain!(
+                    explain,
+                    "Card {} (x{}): {} matches, each copy wins cards {} to {}",
+                    id,
+                    copies,
+                    matches,
+                    cards[won.start].id,
+                    cards[won.end - 1].id
+                );
+            }
+            if won.len() < matches {
+                past_the_end.push(id);
+            }
+
+            for card in &mut cards[won] {
+                card.copies = card
+                    .copies
+                    .checked_add(copies)
+                    .unwrap_or_else(|| panic!("Copies of card {} overflowed u128", card.id));
+            }
+        }
+
+        Cascade {
+            cards,
+            past_the_end,
+        }
+    }
+
+    pub fn solution(input: String) -> u128 {
+        solution_explained(input, &mut Silent)
+    }
+
+    pub fn solution_explained(input: String, explain: &mut dyn Explain) -> u128 {
+        let games = parsing::parse(parse_games, &input);
+        let cascade = cascade(&games, explain);
+
+        if !cascade.past_the_end.is_empty() {
+            explain!(
+                explain,
+                "cards {:?} win copies past the last card, which were left out",
+                cascade.past_the_end
+            );
+        }
+
+        let sum = cascade.total();
+        explain!(explain, "{} cards in total", sum);
+        sum
+    }
+
+    // a card only wins the cards just after it, so only the copies won for the
+    // next few cards need to be kept while reading, anything won past the last
+    // card is never read back
+    pub fn solution_streaming(input: impl BufRead) -> u128 {
+        let mut pending: VecDeque<u128> = VecDeque::new();
+        let mut sum = 0_u128;
+
+        for line in streaming::lines(input) {
+            let game = parsing::parse(parse_line, &line);
+            let copies = 1 + pending.pop_front().unwrap_or(0);
+            let matches = game.matches();
+
+            if pending.len() < matches {
+                pending.resize(matches, 0);
+            }
+            for won in pending.iter_mut().take(matches) {
+                *won = won
+                    .checked_add(copies)
+                    .expect("Copies of a card overflowed u128");
+            }
+            sum = sum
+                .checked_add(copies)
+                .expect("Total number of cards overflowed u128");
+        }
+
+        sum
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            30
+        );
+    }
+
+    #[test]
+    fn streaming_sample() {
+        let file =
+            std::fs::File::open(format!("inputs/{}/part_2/sample.txt", super::DAY_STR)).unwrap();
+        assert_eq!(solution_streaming(std::io::BufReader::new(file)), 30);
+    }
+
+    #[test]
+    fn explained_sample() {
+        let mut steps = explain::Collect::default();
+        solution_explained(
+            std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                .unwrap(),
+            &mut steps,
+        );
+        assert_eq!(
+            steps.0.first().unwrap(),
+            "Card 1 (x1): 4 matches, each copy wins cards 2 to 5"
+        );
+        assert_eq!(steps.0.last().unwrap(), "30 cards in total");
+    }
+
+    #[test]
+    fn wins_past_the_end() {
+        let games = parsing::parse(
+            parse_games,
+            "Card 1: 1 2 | 1 3\nCard 2: 4 5 | 4 5\nCard 3: 6 | 7",
+        );
+        let cascade = cascade(&games, &mut Silent);
+
+        assert_eq!(
+            cascade
+                .cards
+                .iter()
+                .map(|card| (card.id, card.matches, card.copies))
+                .collect::<Vec<_>>(),
+            vec![(1, 1, 1), (2, 2, 2), (3, 0, 3)]
+        );
+        assert_eq!(cascade.past_the_end, vec![2]);
+        assert_eq!(cascade.total(), 6);
+    }
+}
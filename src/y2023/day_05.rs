@@ -0,0 +1,667 @@
+pub const DAY_STR: &str = "2023/day_05";
+
+pub use part_1::solution as part_1;
+pub use part_1::solution_explained as part_1_explained;
+pub use part_2::solution as part_2;
+pub use part_2::solution_explained as part_2_explained;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let blocks = validate::blocks(input);
+    let (seed_block, maps) = blocks.split_first().unwrap();
+
+    let (n, line) = seed_block[0];
+    if let Some(seeds) = problems
+        .prefixed(n, line, "seeds:")
+        .and_then(|seeds| problems.numbers::<u64>(n, seeds))
+    {
+        if seeds.len() % 2 != 0 {
+            problems.at(
+                n,
+                "part 2 reads the seeds as pairs of start and length, found an odd number",
+            );
+        }
+    }
+    if let Some((n, _)) = seed_block.get(1) {
+        problems.at(*n, "expected a blank line after the seeds");
+    }
+
+    if maps.is_empty() {
+        problems.input("expected at least one map after the seeds");
+    }
+
+    let mut category = "seed";
+    for map in maps {
+        let (n, header) = map[0];
+        match header
+            .strip_suffix(" map:")
+            .and_then(|header| header.split_once("-to-"))
+        {
+            Some((from, to)) => {
+                if from != category {
+                    problems.at(
+                        n,
+                        format!(
+                            "map converts from `{}` but the previous map converted to `{}`",
+                            from, category
+                        ),
+                    );
+                }
+                category = to;
+            }
+            None => problems.at(
+                n,
+                format!(
+                    "expected a header like `seed-to-soil map:`, found `{}`",
+                    header
+                ),
+            ),
+        }
+
+        for (n, line) in &map[1..] {
+            if let Some(numbers) = problems.numbers::<u64>(*n, line) {
+                if numbers.len() != 3 {
+                    problems.at(
+                        *n,
+                        format!(
+                            "expected a destination, a source and a length, found {} numbers",
+                            numbers.len()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+    use std::{fmt::Display, ops::RangeInclusive};
+
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{line_ending, not_line_ending, space1},
+        sequence::{preceded, separated_pair, terminated, tuple},
+        IResult,
+    };
+    use tracing::{debug, trace};
+
+    use crate::explain;
+    use crate::explain::{Explain, Silent};
+    use crate::parsing::{self, blank_lines, blocks, lines, unsigned, unsigned_list};
+
+    fn parse_seeds(input: &str) -> IResult<&str, Vec<u64>> {
+        preceded(tag("seeds: "), unsigned_list)(input)
+    }
+
+    #[derive(Debug)]
+    pub struct Map {
+        pub source: u64,
+        pub destination: u64,
+        pub range: u64,
+    }
+
+    fn parse_map_line(input: &str) -> IResult<&str, Map> {
+        let (input, (destination, _, source, _, range)) =
+            tuple((unsigned, space1, unsigned, space1, unsigned))(input)?;
+
+        Ok((
+            input,
+            Map {
+                source,
+                destination,
+                range,
+            },
+        ))
+    }
+
+    fn parse_map(input: &str) -> IResult<&str, Vec<Map>> {
+        // the maps are always in order, so the header can be skipped
+        let (input, map) = preceded(
+            terminated(not_line_ending, line_ending),
+            lines(parse_map_line),
+        )(input)?;
+
+        trace!(?map, "parsed map");
+        Ok((input, map))
+    }
+
+    pub fn parse_almanac(input: &str) -> IResult<&str, (Vec<u64>, Vec<Vec<Map>>)> {
+        separated_pair(parse_seeds, blank_lines, blocks(parse_map))(input)
+    }
+
+    // one past the largest seed, pieces are in u128 so the last one can end
+    // here and still include u64::MAX
+    const END: u128 = u64::MAX as u128 + 1;
+
+    // `start..end` is moved by `offset`, so `x` goes to `x + offset`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Piece {
+        pub start: u128,
+        pub end: u128,
+        pub offset: i128,
+    }
+
+    impl Piece {
+        fn apply(&self, x: u128) -> u128 {
+            x.checked_add_signed(self.offset).expect("Mapped below 0")
+        }
+
+        fn unapply(&self, x: u128) -> u128 {
+            x.checked_add_signed(-self.offset).expect("Mapped below 0")
+        }
+    }
+
+    fn to_u64(x: u128) -> u64 {
+        u64::try_from(x).expect("Mapped outside of the range of u64")
+    }
+
+    // Every layer of maps composed into one function from seed to location.
+    // The pieces are sorted, don't overlap and cover every u64 up to `END`,
+    // anything not in a map is a piece with an offset of 0.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Almanac {
+        pub pieces: Vec<Piece>,
+    }
+
+    impl Almanac {
+        pub fn identity() -> Self {
+            Almanac {
+                pieces: vec![Piece {
+                    start: 0,
+                    end: END,
+                    offset: 0,
+                }],
+            }
+        }
+
+        pub fn new(layers: &[Vec<Map>]) -> Self {
+            layers.iter().fold(Almanac::identity(), |almanac, layer| {
+                almanac.then(&Almanac::layer(layer))
+            })
+        }
+
+        // a single layer of maps, when maps overlap the one starting first wins
+        fn layer(maps: &[Map]) -> Self {
+            let mut maps = maps.iter().collect::<Vec<_>>();
+            maps.sort_by_key(|map| map.source);
+
+            let mut pieces = vec![];
+            let mut covered = 0;
+            for map in maps {
+                let start = (map.source as u128).max(covered);
+                let end = map.source as u128 + map.range as u128;
+                if start >= end {
+                    continue;
+                }
+                if covered < start {
+                    pieces.push(Piece {
+                        start: covered,
+                        end: start,
+                        offset: 0,
+                    });
+                }
+                pieces.push(Piece {
+                    start,
+                    end,
+                    offset: map.destination as i128 - map.source as i128,
+                });
+                covered = end;
+            }
+            pieces.push(Piece {
+                start: covered,
+                end: END,
+                offset: 0,
+            });
+
+            Almanac::merged(pieces)
+        }
+
+        // applies `self` and then `next`
+        pub fn then(&self, next: &Almanac) -> Self {
+            let mut pieces = vec![];
+            for piece in &self.pieces {
+                let image = piece.apply(piece.start)..piece.apply(piece.end);
+                let first = next.pieces.partition_point(|n| n.end <= image.start);
+                for n in next.pieces[first..]
+                    .iter()
+                    .take_while(|n| n.start < image.end)
+                {
+                    pieces.push(Piece {
+                        start: piece.unapply(image.start.max(n.start)),
+                        end: piece.unapply(image.end.min(n.end)),
+                        offset: piece.offset + n.offset,
+                    });
+                }
+                // past the end of `next` nothing moves, so every seed is still
+                // covered even if it goes past u64::MAX
+                if image.end > END {
+                    pieces.push(Piece {
+                        start: piece.unapply(image.start.max(END)),
+                        end: piece.end,
+                        offset: piece.offset,
+                    });
+                }
+            }
+            pieces.sort_by_key(|piece| piece.start);
+
+            Almanac::merged(pieces)
+        }
+
+        // joins neighbouring pieces that move by the same offset
+        fn merged(pieces: Vec<Piece>) -> Self {
+            let mut merged: Vec<Piece> = vec![];
+            for piece in pieces {
+                match merged.last_mut() {
+                    Some(last) if last.end == piece.start && last.offset == piece.offset => {
+                        last.end = piece.end;
+                    }
+                    _ => merged.push(piece),
+                }
+            }
+            Almanac { pieces: merged }
+        }
+
+        pub fn location(&self, seed: u64) -> u64 {
+            let seed = seed as u128;
+            to_u64(self.pieces[self.pieces.partition_point(|piece| piece.end <= seed)].apply(seed))
+        }
+
+        // the lowest location of the `len` seeds from `start`, the lowest
+        // location in a piece is always at its start, so only the pieces the
+        // seeds cover are looked at
+        pub fn lowest_location(&self, start: u64, len: u64) -> Option<u64> {
+            if len == 0 {
+                return None;
+            }
+            let (start, end) = (start as u128, start as u128 + len as u128);
+            let first = self.pieces.partition_point(|piece| piece.end <= start);
+            self.pieces[first..]
+                .iter()
+                .take_while(|piece| piece.start < end)
+                .map(|piece| piece.apply(piece.start.max(start)))
+                .min()
+                .map(to_u64)
+        }
+
+        // every range of seeds that ends up somewhere in `locations`, sorted by
+        // their start
+        pub fn seeds_for(&self, locations: RangeInclusive<u64>) -> Vec<RangeInclusive<u64>> {
+            let mut seeds: Vec<RangeInclusive<u64>> = vec![];
+            for piece in &self.pieces {
+                let image = piece.apply(piece.start)..piece.apply(piece.end);
+                let start = image.start.max(*locations.start() as u128);
+                let end = image.end.min(*locations.end() as u128 + 1);
+                if start >= end {
+                    continue;
+                }
+                let range = to_u64(piece.unapply(start))..=to_u64(piece.unapply(end) - 1);
+                match seeds.last_mut() {
+                    Some(last) if *last.end() as u128 + 1 == *range.start() as u128 => {
+                        *last = *last.start()..=*range.end()
+                    }
+                    _ => seeds.push(range),
+                }
+            }
+            seeds
+        }
+    }
+
+    impl Display for Almanac {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            for piece in &self.pieces {
+                writeln!(
+                    f,
+                    "seeds {}..{} go to {}..{} ({:+})",
+                    piece.start,
+                    piece.end,
+                    piece.apply(piece.start),
+                    piece.apply(piece.end),
+                    piece.offset
+                )?;
+            }
+            Ok(())
+        }
+    }
+
+    pub fn solution(input: String) -> u64 {
+        solution_explained(input, &mut Silent)
+    }
+
+    pub fn solution_explained(input: String, explain: &mut dyn Explain) -> u64 {
+        let (seeds, maps) = parsing::parse(parse_almanac, &input);
+        debug!(?seeds, "parsed seeds");
+        debug!(layers = maps.len(), "parsed maps");
+
+        let almanac = Almanac::new(&maps);
+        explain!(
+            explain,
+            "{} layers of maps compose into {} pieces",
+            maps.len(),
+            almanac.pieces.len()
+        );
+        if explain.enabled() {
+            for line in almanac.to_string().lines() {
+                explain!(explain, "{}", line);
+            }
+        }
+
+        seeds
+            .iter()
+            .map(|seed| {
+                let location = almanac.location(*seed);
+                trace!(seed, location, "seed reached location");
+                explain!(explain, "seed {} goes to location {}", seed, location);
+                location
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            35
+        );
+    }
+
+    #[test]
+    fn composed() {
+        let (_, maps) = parsing::parse(
+            parse_almanac,
+            &std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                .unwrap(),
+        );
+        let almanac = Almanac::new(&maps);
+
+        // the example from the puzzle, one seed at a time
+        assert_eq!(
+            [79, 14, 55, 13].map(|seed| almanac.location(seed)),
+            [82, 43, 86, 35]
+        );
+        assert_eq!(almanac.lowest_location(79, 14), Some(46));
+        assert_eq!(almanac.lowest_location(5, 0), None);
+
+        for seeds in almanac.seeds_for(46..=46) {
+            for seed in seeds {
+                assert_eq!(almanac.location(seed), 46);
+            }
+        }
+        assert_eq!(almanac.seeds_for(0..=u64::MAX).len(), 1);
+
+        // the last seed is covered too
+        assert_eq!(almanac.location(u64::MAX), u64::MAX);
+        assert_eq!(almanac.lowest_location(u64::MAX, 1), Some(u64::MAX));
+        let top = Almanac::new(&[vec![Map {
+            destination: 0,
+            source: u64::MAX - 1,
+            range: 2,
+        }]]);
+        assert_eq!(top.location(u64::MAX), 1);
+        assert_eq!(top.seeds_for(1..=1), vec![1..=1, u64::MAX..=u64::MAX]);
+
+        let table = almanac.to_string();
+        assert_eq!(table.lines().count(), almanac.pieces.len());
+        assert!(table.starts_with("seeds 0..14 go to 22..36 (+22)\n"));
+    }
+}
+
+mod part_2 {
+
+    use std::mem::swap;
+
+    use super::part_1::parse_almanac;
+    use super::part_1::{Almanac, Map};
+
+    use tracing::{debug, trace};
+
+    use crate::explain;
+    use crate::explain::{Explain, Silent};
+    use crate::parsing;
+
+    #[derive(Debug, Clone, Copy)]
+    struct SeedRange {
+        start: u64,
+        end: u64,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct TransformedSeedRangeSuccessType {
+        transformed: SeedRange,
+        excluded_before: Option<SeedRange>,
+        excluded_after: Option<SeedRange>,
+    }
+
+    impl Map {
+        fn source_end(&self) -> u64 {
+            self.source + self.range
+        }
+        fn destination_end(&self) -> u64 {
+            self.destination + self.range
+        }
+        fn source_contains(&self, val: u64) -> bool {
+            self.source <= val && val < self.source_end()
+        }
+
+        fn translate_seed_range(&self, sr: &SeedRange) -> Option<TransformedSeedRangeSuccessType> {
+            let m_start = self.source;
+            let m_end = self.source_end();
+            let s_start = sr.start;
+            let s_end = sr.end;
+
+            // let t_offset = self.source - self.destination;
+
+            // leaking both
+            if s_start < m_start && m_end < s_end {
+                return Some(TransformedSeedRangeSuccessType {
+                    transformed: SeedRange {
+                        start: m_start - self.source + self.destination,
+                        end: m_end - self.source + self.destination,
+                    },
+                    excluded_before: Some(SeedRange {
+                        start: s_start,
+                        end: m_start,
+                    }),
+                    excluded_after: Some(SeedRange {
+                        start: m_end,
+                        end: s_end,
+                    }),
+                });
+            }
+
+            // leaking after
+            if m_start <= s_start && s_start < m_end && m_end < s_end {
+                return Some(TransformedSeedRangeSuccessType {
+                    transformed: SeedRange {
+                        start: s_start - self.source + self.destination,
+                        end: m_end - self.source + self.destination,
+                    },
+                    excluded_before: None,
+                    excluded_after: Some(SeedRange {
+                        start: m_end,
+                        end: s_end,
+                    }),
+                });
+            }
+
+            // leaking before
+            if s_start < m_start && m_start < s_end && s_end <= m_end {
+                return Some(TransformedSeedRangeSuccessType {
+                    transformed: SeedRange {
+                        start: m_start - self.source + self.destination,
+                        end: s_end - self.source + self.destination,
+                    },
+                    excluded_before: Some(SeedRange {
+                        start: s_start,
+                        end: m_start,
+                    }),
+                    excluded_after: None,
+                });
+            }
+
+            // contained
+            if m_start <= s_start && s_end <= m_end {
+                return Some(TransformedSeedRangeSuccessType {
+                    transformed: SeedRange {
+                        start: s_start - self.source + self.destination,
+                        end: s_end - self.source + self.destination,
+                    },
+                    excluded_before: None,
+                    excluded_after: None,
+                });
+            }
+
+            None
+
+            // todo!("THis should never be reached")
+        }
+    }
+
+    pub fn solution(input: String) -> u64 {
+        solution_explained(input, &mut Silent)
+    }
+
+    pub fn solution_explained(input: String, explain: &mut dyn Explain) -> u64 {
+        let (seeds, maps) = parsing::parse(parse_almanac, &input);
+
+        let almanac = Almanac::new(&maps);
+        explain!(
+            explain,
+            "{} layers of maps compose into {} pieces",
+            maps.len(),
+            almanac.pieces.len()
+        );
+        if explain.enabled() {
+            for line in almanac.to_string().lines() {
+                explain!(explain, "{}", line);
+            }
+        }
+
+        seeds
+            .chunks(2)
+            .map(|pair| {
+                let location = almanac.lowest_location(pair[0], pair[1]).unwrap();
+                debug!(
+                    start = pair[0],
+                    len = pair[1],
+                    location,
+                    "lowest location for seed range"
+                );
+                explain!(
+                    explain,
+                    "seeds {}..{} reach location {} at the lowest",
+                    pair[0],
+                    pair[0] as u128 + pair[1] as u128,
+                    location
+                );
+                location
+            })
+            .min()
+            .unwrap()
+    }
+
+    // moves the seed ranges through one layer at a time, kept to check the
+    // composed almanac against
+    fn solution_by_layers(input: String) -> u64 {
+        let (seeds, maps) = parsing::parse(parse_almanac, &input);
+
+        let seeds = seeds
+            .chunks(2)
+            .map(|se| SeedRange {
+                start: se[0],
+                end: se[0] + se[1] - 1,
+            })
+            .collect::<Vec<_>>();
+        debug!(?seeds, "parsed seed ranges");
+
+        let mut seed_vec_a: Vec<SeedRange> = seeds.clone();
+        let mut seed_vec_b: Vec<SeedRange> = vec![];
+
+        let old_seeds = &mut seed_vec_a;
+        let new_seeds = &mut seed_vec_b;
+        swap(old_seeds, new_seeds);
+
+        for (layer, map) in maps.iter().enumerate() {
+            swap(old_seeds, new_seeds);
+            for m in map {
+                // let seeds_to_check = old_seeds.clone();
+                let mut skipped_seeds: Vec<SeedRange> = vec![];
+
+                old_seeds.iter().for_each(|os| {
+                    assert!(os.start < os.end);
+                });
+
+                while let Some(seed) = old_seeds.pop() {
+                    let x = m.translate_seed_range(&seed);
+                    trace!(?m, ?seed, ?x, "translated seed range");
+                    match x {
+                        Some(res) => {
+                            new_seeds.push(res.transformed);
+                            if let Some(eb) = res.excluded_before {
+                                skipped_seeds.push(eb);
+                            }
+                            if let Some(ea) = res.excluded_after {
+                                skipped_seeds.push(ea);
+                            }
+                        }
+                        None => skipped_seeds.push(seed),
+                    }
+                }
+
+                *old_seeds = skipped_seeds;
+            }
+
+            new_seeds.append(old_seeds);
+            old_seeds.clear();
+            debug!(layer, ranges = new_seeds.len(), "mapped seed ranges");
+        }
+
+        let x = new_seeds.iter().map(|ns| ns.start).min().unwrap();
+
+        // todo!();
+        x
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            46
+        );
+    }
+
+    #[test]
+    fn composed_matches_layers() {
+        let input = std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+            .unwrap();
+        assert_eq!(solution(input.clone()), solution_by_layers(input));
+    }
+
+    #[test]
+    fn contains() {
+        let map = Map {
+            source: 55,
+            destination: 50,
+            range: 2,
+        };
+
+        let s = SeedRange { start: 55, end: 67 };
+
+        let x = map.translate_seed_range(&s);
+        dbg!(x);
+    }
+}
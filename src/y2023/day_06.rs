@@ -0,0 +1,207 @@
+pub const DAY_STR: &str = "2023/day_06";
+
+pub use part_1::solution as part_1;
+pub use part_2::solution as part_2;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let lines = validate::lines(input).collect::<Vec<_>>();
+    let [(time_n, time), (distance_n, distance)] = lines[..] else {
+        problems.input(format!(
+            "expected a `Time:` and a `Distance:` line, found {} lines",
+            lines.len()
+        ));
+        return problems.into_vec();
+    };
+
+    let times = problems
+        .prefixed(time_n, time, "Time:")
+        .and_then(|times| problems.numbers::<u64>(time_n, times));
+    let distances = problems
+        .prefixed(distance_n, distance, "Distance:")
+        .and_then(|distances| problems.numbers::<u64>(distance_n, distances));
+
+    if let (Some(times), Some(distances)) = (times, distances) {
+        if times.len() != distances.len() {
+            problems.at(
+                distance_n,
+                format!(
+                    "found {} distances for {} times",
+                    distances.len(),
+                    times.len()
+                ),
+            );
+        }
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+    use std::iter::zip;
+
+    use nom::{
+        bytes::complete::{tag, take_until},
+        character::complete::{self, multispace1},
+        multi::many1,
+        sequence::preceded,
+        IResult,
+    };
+
+    pub fn parse_line(input: &str) -> IResult<&str, Vec<u64>> {
+        // dbg!(input);
+        let (input, _) = take_until(":")(input)?;
+        // dbg!(input);
+        let (input, _) = tag(":")(input)?;
+        // dbg!(input);
+        let (input, x) = many1(preceded(multispace1, complete::u64))(input)?;
+        // dbg!(input);
+        // todo!()
+        Ok((input, x))
+    }
+
+    pub fn distance_traveled(charge_time: u64, total_time: u64) -> u64 {
+        assert!(charge_time <= total_time);
+
+        let speed = charge_time;
+        let move_time = total_time - charge_time;
+
+        move_time * speed
+    }
+
+    // tries every charge time, only used to check `ways_to_beat` against
+    pub fn ways_to_beat_by_counting(max_time: u64, record: u64) -> u64 {
+        let mut ways_beat = 0;
+        for t in 0..max_time {
+            let dist = distance_traveled(t, max_time);
+            if dist > record {
+                ways_beat += 1;
+            }
+        }
+        ways_beat
+    }
+
+    // Charging for `t` of the `max_time` travels `t * (max_time - t)`, which beats
+    // the record while `t² - max_time * t + record < 0`, so between the roots of
+    // that quadratic. The square root is rounded down and then the first winning
+    // charge time is found by stepping from there, so the answer is exact. The
+    // distances are symmetric around `max_time / 2`, which gives the last one.
+    pub fn ways_to_beat(max_time: u64, record: u64) -> u64 {
+        let (time, record) = (max_time as u128, record as u128);
+        let distance = |charge: u128| charge * (time - charge);
+
+        // the longest distance is at the middle, if that ties or loses then
+        // nothing wins
+        if distance(time / 2) <= record {
+            return 0;
+        }
+
+        // record < time² / 4 here, so this can't underflow
+        let root = (time * time - 4 * record).isqrt();
+        let mut first = (time - root) / 2;
+        while distance(first) <= record {
+            first += 1;
+        }
+        while first > 0 && distance(first - 1) > record {
+            first -= 1;
+        }
+
+        (time + 1 - 2 * first) as u64
+    }
+
+    pub fn solution(input: String) -> u64 {
+        // dbg!(&input);
+        let (input, times) = parse_line(&input).unwrap();
+        let (_input, records) = parse_line(input).unwrap();
+
+        // dbg!(&times);
+        // dbg!(&records);
+
+        let x = zip(times, records)
+            .map(|(max_time, record)| ways_to_beat(max_time, record))
+            .collect::<Vec<_>>();
+
+        // dbg!(&x);
+
+        // todo!()
+        x.iter().product()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            288
+        );
+    }
+
+    #[test]
+    fn edge_cases() {
+        // ties with the record don't count
+        assert_eq!(ways_to_beat(10, 24), 1);
+        assert_eq!(ways_to_beat(10, 25), 0);
+        assert_eq!(ways_to_beat(0, 0), 0);
+        assert_eq!(ways_to_beat(2, 0), 1);
+        assert_eq!(ways_to_beat(u64::MAX, 0), u64::MAX - 1);
+        assert_eq!(ways_to_beat(u64::MAX, u64::MAX), u64::MAX - 3);
+    }
+
+    #[test]
+    fn test_dist() {
+        assert_eq!(distance_traveled(10, 10), 0);
+        assert_eq!(distance_traveled(0, 10), 0);
+        assert_eq!(distance_traveled(5, 10), 25);
+        assert_eq!(distance_traveled(4, 10), 24);
+        assert_eq!(distance_traveled(8, 10), 16);
+    }
+}
+
+mod part_2 {
+    use std::iter::zip;
+
+    use super::part_1::{parse_line, ways_to_beat};
+
+    pub fn solution(input: String) -> u64 {
+        let (input, times) = parse_line(&input).unwrap();
+        let (_input, records) = parse_line(input).unwrap();
+
+        zip(times, records)
+            .map(|(max_time, record)| ways_to_beat(max_time, record))
+            .product()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            71503
+        );
+    }
+
+    #[cfg(test)]
+    mod differential {
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn matches_brute_force(max_time in 0_u64..500, record in 0_u64..70_000) {
+                prop_assert_eq!(
+                    super::ways_to_beat(max_time, record),
+                    super::super::part_1::ways_to_beat_by_counting(max_time, record)
+                );
+            }
+        }
+    }
+}
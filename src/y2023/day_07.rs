@@ -0,0 +1,366 @@
+pub const DAY_STR: &str = "2023/day_07";
+
+pub use part_1::solution as part_1;
+pub use part_1::solution_explained as part_1_explained;
+pub use part_2::solution as part_2;
+pub use part_2::solution_explained as part_2_explained;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    for (n, line) in validate::lines(input) {
+        let Some((hand, bid)) = line.split_once(' ') else {
+            problems.at(n, "expected a hand and a bid separated by a space");
+            continue;
+        };
+        if hand.chars().count() != 5 || hand.chars().any(|c| !"23456789TJQKA".contains(c)) {
+            problems.at(
+                n,
+                format!(
+                    "`{}` is not a hand of five cards from `23456789TJQKA`",
+                    hand
+                ),
+            );
+        }
+        problems.number::<u32>(n, bid);
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+    use std::{cmp::Ordering, collections::HashMap, fmt::Display};
+
+    use itertools::Itertools;
+
+    use crate::explain;
+    use crate::explain::{Explain, Silent};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Hand(pub Vec<char>);
+
+    impl From<&str> for Hand {
+        fn from(value: &str) -> Self {
+            Hand(value.chars().collect())
+        }
+    }
+
+    impl Display for Hand {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.iter().try_for_each(|card| write!(f, "{}", card))
+        }
+    }
+
+    #[test]
+    fn from_str_hand() {
+        assert_eq!(
+            dbg!(Hand::from("AAAAT")),
+            Hand(vec!['A', 'A', 'A', 'A', 'T'])
+        )
+    }
+
+    // How a game of Camel Cards is played, so the same sorting works for the
+    // puzzle's rules and for variants with other cards, more wild cards or
+    // bigger hands.
+    #[derive(Debug, Clone)]
+    pub struct CamelCardsRules {
+        // every card, from the weakest to the strongest
+        pub ordering: Vec<char>,
+        // cards that act like whichever card makes the hand strongest
+        pub wild: Vec<char>,
+        pub hand_size: usize,
+        // the kinds of hand from the weakest to the strongest, along with the
+        // smallest groups of equal cards they need, largest group first, so a
+        // full house is `[3, 2]`
+        pub hand_types: Vec<(String, Vec<usize>)>,
+    }
+
+    impl CamelCardsRules {
+        // the rules from part 1
+        pub fn standard() -> Self {
+            CamelCardsRules {
+                ordering: "23456789TJQKA".chars().collect(),
+                wild: vec![],
+                hand_size: 5,
+                hand_types: [
+                    ("HighCard", vec![]),
+                    ("OnePair", vec![2]),
+                    ("TwoPairs", vec![2, 2]),
+                    ("ThreeOfAKind", vec![3]),
+                    ("FullHouse", vec![3, 2]),
+                    ("FourOfAKind", vec![4]),
+                    ("FiveOfAKind", vec![5]),
+                ]
+                .into_iter()
+                .map(|(name, groups)| (name.to_string(), groups))
+                .collect(),
+            }
+        }
+
+        // the rules from part 2, `J` is now a joker and the weakest card
+        pub fn jokers() -> Self {
+            CamelCardsRules {
+                ordering: "J23456789TQKA".chars().collect(),
+                wild: vec!['J'],
+                ..CamelCardsRules::standard()
+            }
+        }
+
+        fn card_value(&self, card: char) -> usize {
+            self.ordering
+                .iter()
+                .position(|c| *c == card)
+                .unwrap_or_else(|| panic!("`{}` is not one of the cards in play", card))
+        }
+
+        // the sizes of the groups of equal cards, largest first, after the wild
+        // cards have joined whichever groups give the strongest hand type
+        fn best_type(&self, groups: &mut Vec<usize>, wild: usize) -> usize {
+            if wild == 0 {
+                let sorted = groups.iter().copied().sorted().rev().collect_vec();
+                return self
+                    .hand_types
+                    .iter()
+                    .rposition(|(_, needed)| {
+                        needed.len() <= sorted.len()
+                            && needed.iter().zip(&sorted).all(|(n, g)| g >= n)
+                    })
+                    .expect("No hand type matches, the weakest type should need nothing");
+            }
+
+            // a wild card either joins an existing group or starts its own
+            let mut best = 0;
+            for i in 0..groups.len() {
+                groups[i] += 1;
+                best = best.max(self.best_type(groups, wild - 1));
+                groups[i] -= 1;
+            }
+            groups.push(1);
+            best = best.max(self.best_type(groups, wild - 1));
+            groups.pop();
+            best
+        }
+
+        // how strong the type of `hand` is, as an index into `hand_types`
+        pub fn hand_type(&self, hand: &Hand) -> usize {
+            assert_eq!(
+                hand.0.len(),
+                self.hand_size,
+                "{} doesn't have {} cards",
+                hand,
+                self.hand_size
+            );
+
+            let mut counts = HashMap::new();
+            let mut wild = 0;
+            for card in &hand.0 {
+                self.card_value(*card);
+                if self.wild.contains(card) {
+                    wild += 1;
+                } else {
+                    *counts.entry(card).or_insert(0) += 1;
+                }
+            }
+
+            self.best_type(&mut counts.into_values().collect(), wild)
+        }
+
+        pub fn hand_type_name(&self, hand: &Hand) -> &str {
+            &self.hand_types[self.hand_type(hand)].0
+        }
+
+        // by hand type, then card by card from the first
+        pub fn compare(&self, a: &Hand, b: &Hand) -> Ordering {
+            self.sort_key(a).cmp(&self.sort_key(b))
+        }
+
+        fn sort_key(&self, hand: &Hand) -> (usize, Vec<usize>) {
+            (
+                self.hand_type(hand),
+                hand.0.iter().map(|card| self.card_value(*card)).collect(),
+            )
+        }
+
+        // from the weakest hand to the strongest
+        pub fn sort(&self, hands: &mut [Hand]) {
+            hands.sort_by_cached_key(|hand| self.sort_key(hand));
+        }
+
+        // each bid times the rank of its hand, added up
+        pub fn winnings(&self, input: &str, explain: &mut dyn Explain) -> u32 {
+            let mut hand_n_bids = input
+                .lines()
+                .map(|line| {
+                    let (cards, bid) = line
+                        .split_once(' ')
+                        .expect("Expected a hand and a bid separated by a space");
+                    let hand = Hand::from(cards);
+                    // kept so the hand type is only worked out once
+                    let key = self.sort_key(&hand);
+                    (
+                        key,
+                        hand,
+                        bid.parse::<u32>().expect("Failed to parse bid to u32"),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            hand_n_bids.sort_by(|a, b| a.0.cmp(&b.0));
+
+            hand_n_bids
+                .iter()
+                .enumerate()
+                .map(|(index, ((hand_type, _), hand, bid))| {
+                    let rank = index as u32 + 1;
+                    explain!(
+                        explain,
+                        "Rank {}: {} ({}), bid {} wins {}",
+                        rank,
+                        hand,
+                        self.hand_types[*hand_type].0,
+                        bid,
+                        rank * bid
+                    );
+                    rank * bid
+                })
+                .sum::<u32>()
+        }
+    }
+
+    #[test]
+    fn hand_strength() {
+        let mut hands = vec![
+            Hand::from("32T3K"),
+            Hand::from("T55J5"),
+            Hand::from("KK677"),
+            Hand::from("KTJJT"),
+            Hand::from("QQQJA"),
+            Hand::from("QQQJ2"),
+        ];
+        CamelCardsRules::standard().sort(&mut hands);
+        assert_eq!(
+            hands,
+            vec![
+                Hand::from("32T3K"),
+                Hand::from("KTJJT"),
+                Hand::from("KK677"),
+                Hand::from("T55J5"),
+                Hand::from("QQQJ2"),
+                Hand::from("QQQJA"),
+            ]
+        );
+    }
+
+    #[test]
+    fn other_rules() {
+        // two kinds of joker, and six cards in a hand
+        let mut rules = CamelCardsRules::jokers();
+        rules.ordering.insert(0, '*');
+        rules.wild.push('*');
+        rules.hand_size = 6;
+        rules
+            .hand_types
+            .push(("TwoTriples".to_string(), vec![3, 3]));
+        rules.hand_types.push(("SixOfAKind".to_string(), vec![6]));
+
+        assert_eq!(rules.hand_type_name(&Hand::from("J*2345")), "ThreeOfAKind");
+        assert_eq!(rules.hand_type_name(&Hand::from("22J33*")), "TwoTriples");
+        assert_eq!(rules.hand_type_name(&Hand::from("J*J*JA")), "SixOfAKind");
+        assert_eq!(
+            rules.compare(&Hand::from("*22333"), &Hand::from("J22333")),
+            Ordering::Less
+        );
+
+        // aces low
+        let mut rules = CamelCardsRules::standard();
+        rules.ordering.rotate_right(1);
+        assert_eq!(
+            rules.compare(&Hand::from("A2345"), &Hand::from("23456")),
+            Ordering::Less
+        );
+    }
+
+    pub fn solution(input: String) -> u32 {
+        solution_explained(input, &mut Silent)
+    }
+
+    pub fn solution_explained(input: String, explain: &mut dyn Explain) -> u32 {
+        CamelCardsRules::standard().winnings(&input, explain)
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            6440
+        );
+    }
+}
+
+mod part_2 {
+    use super::part_1::CamelCardsRules;
+
+    use crate::explain::{Explain, Silent};
+
+    #[cfg(test)]
+    use super::part_1::Hand;
+
+    pub fn solution(input: String) -> u32 {
+        solution_explained(input, &mut Silent)
+    }
+
+    pub fn solution_explained(input: String, explain: &mut dyn Explain) -> u32 {
+        CamelCardsRules::jokers().winnings(&input, explain)
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            5905
+        );
+    }
+
+    #[test]
+    fn hand_strength_with_jokers() {
+        let mut hands: Vec<Hand> = vec![
+            Hand::from("32T3K"),
+            Hand::from("T55J5"),
+            Hand::from("KK677"),
+            Hand::from("KTJJT"),
+            Hand::from("QQQJA"),
+        ];
+        CamelCardsRules::jokers().sort(&mut hands);
+        dbg!(&hands);
+        assert_eq!(
+            hands,
+            vec![
+                Hand::from("32T3K"),
+                Hand::from("KK677"),
+                Hand::from("T55J5"),
+                Hand::from("QQQJA"),
+                Hand::from("KTJJT"),
+            ]
+        );
+    }
+}
+
+// too high    251627483
+// mistake in mapping TwoPairs + 2 Jokers to Full House
+
+// too low     251342183
+// used jokers to make OnePair,
+// then used them again to upgrade that into a four of a kind
+
+// just right  251515496
@@ -0,0 +1,420 @@
+pub const DAY_STR: &str = "2023/day_08";
+
+pub use part_1::solution as part_1;
+pub use part_2::solution as part_2;
+
+use itertools::Itertools;
+use std::collections::HashMap;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let blocks = validate::blocks(input);
+    let [directions, nodes] = &blocks[..] else {
+        problems.input("expected the directions, a blank line and then the nodes");
+        return problems.into_vec();
+    };
+
+    for (n, line) in directions {
+        if let Some(c) = line.chars().find(|c| *c != 'L' && *c != 'R') {
+            problems.at(
+                *n,
+                format!("unexpected direction `{}`, expected `L` or `R`", c),
+            );
+        }
+    }
+    if let Some((n, _)) = directions.get(1) {
+        problems.at(*n, "the directions should be on a single line");
+    }
+
+    let mut defined = HashMap::new();
+    let mut edges = vec![];
+    for (n, line) in nodes {
+        let parsed = line.split_once(" = (").and_then(|(node, rest)| {
+            let (left, right) = rest.strip_suffix(')')?.split_once(", ")?;
+            Some((node, left, right))
+        });
+        let Some((node, left, right)) = parsed else {
+            problems.at(
+                *n,
+                format!("expected a node like `AAA = (BBB, CCC)`, found `{}`", line),
+            );
+            continue;
+        };
+        for name in [node, left, right] {
+            if name.len() != 3 || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                problems.at(*n, format!("`{}` is not a three character node name", name));
+            }
+        }
+        if let Some(first) = defined.insert(node, *n) {
+            problems.at(
+                *n,
+                format!("node `{}` is already defined on line {}", node, first),
+            );
+        }
+        edges.push((*n, left, right));
+    }
+
+    for (n, left, right) in edges {
+        for name in [left, right].into_iter().dedup() {
+            if !defined.contains_key(name) {
+                problems.at(n, format!("node `{}` is never defined", name));
+            }
+        }
+    }
+    if !defined.keys().any(|node| node.ends_with('A')) {
+        problems.input("there are no starting nodes ending in `A`");
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+    use std::{collections::HashMap, fmt::Debug};
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Node(pub [char; 3]);
+
+    impl From<&str> for Node {
+        fn from(value: &str) -> Self {
+            Node([
+                value.chars().nth(0).unwrap(),
+                value.chars().nth(1).unwrap(),
+                value.chars().nth(2).unwrap(),
+            ])
+        }
+    }
+
+    impl Debug for Node {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_fmt(format_args!("{}{}{}", &self.0[0], &self.0[1], &self.0[2]))
+        }
+    }
+
+    pub fn solution(input: String) -> usize {
+        // dbg!(&input);
+        let (dirs, input) = &input.split_once("\n\n").unwrap();
+        // dbg!(&dirs);
+        let (l_map, r_map) = input
+            .split_terminator('\n')
+            .map(|line| {
+                (
+                    Node::from(&line[0..3]),
+                    Node::from(&line[7..10]),
+                    Node::from(&line[12..15]),
+                )
+            })
+            .fold(
+                (HashMap::new(), HashMap::new()),
+                |(mut l_map, mut r_map), edge| {
+                    l_map.insert(edge.0, edge.1);
+                    r_map.insert(edge.0, edge.2);
+                    (l_map, r_map)
+                },
+            );
+
+        // dbg!("{:?}", &l_map);
+        // dbg!("{:?}", &r_map);
+
+        let x = dirs.chars().cycle().enumerate();
+
+        let mut current_node = Node::from("AAA");
+
+        for (iter, dir) in x {
+            // dbg!(iter);
+            current_node = match dir {
+                'L' => *l_map.get(&current_node).unwrap(),
+                'R' => *r_map.get(&current_node).unwrap(),
+                x => panic!("Unexpected direction {x}"),
+            };
+            // dbg!(current_node);
+
+            if current_node == Node::from("ZZZ") {
+                // dbg!(iter + 1);
+                return iter + 1;
+            }
+
+            if iter > 100000 {
+                break;
+            }
+        }
+
+        panic!("Failed to find solution within max iterations");
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn sample_2() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample2.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            6
+        );
+    }
+}
+
+mod part_2 {
+
+    use std::collections::{BTreeSet, HashMap};
+
+    use super::part_1::Node;
+
+    pub fn lcm(a: usize, b: usize) -> usize {
+        let mut x;
+        let mut y;
+
+        if a > b {
+            x = a;
+            y = b;
+        } else {
+            x = b;
+            y = a;
+        }
+
+        let mut rem = x % y;
+
+        while rem != 0 {
+            x = y;
+            y = rem;
+            rem = x % y;
+        }
+
+        a * b / y
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 5), 20);
+        assert_eq!(lcm(12, 16), 48);
+        assert_eq!(lcm(10, 9), 90);
+    }
+
+    pub type Network = HashMap<Node, (Node, Node)>;
+
+    pub fn parse_network(input: &str) -> (Vec<char>, Network) {
+        let (dirs, input) = input.split_once("\n\n").unwrap();
+        let network = input
+            .split_terminator('\n')
+            .map(|line| {
+                (
+                    Node::from(&line[0..3]),
+                    (Node::from(&line[7..10]), Node::from(&line[12..15])),
+                )
+            })
+            .collect();
+
+        (dirs.trim().chars().collect(), network)
+    }
+
+    // The steps at which one ghost is on a node ending in `Z`. Its walk ends up
+    // going round a cycle once it's back on a node at the same point in the
+    // directions, so it's on a `Z` at each step in `prefix` before the cycle
+    // starts, and at each step in `cycle` plus any multiple of `period` after.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Walk {
+        pub prefix: Vec<u64>,
+        pub cycle_start: u64,
+        pub period: u64,
+        pub cycle: Vec<u64>,
+    }
+
+    impl Walk {
+        pub fn new(dirs: &[char], network: &Network, start: Node) -> Self {
+            let mut seen = HashMap::new();
+            let mut hits = vec![];
+            let mut node = start;
+
+            for step in 0_u64.. {
+                let instruction = step as usize % dirs.len();
+                if let Some(cycle_start) = seen.insert((node, instruction), step) {
+                    let (prefix, cycle) = hits.iter().partition(|hit| **hit < cycle_start);
+                    return Walk {
+                        prefix,
+                        cycle_start,
+                        period: step - cycle_start,
+                        cycle,
+                    };
+                }
+
+                if step > 0 && node.0[2] == 'Z' {
+                    hits.push(step);
+                }
+
+                let (left, right) = network
+                    .get(&node)
+                    .unwrap_or_else(|| panic!("Node {:?} is not in the network", node));
+                node = match dirs[instruction] {
+                    'L' => *left,
+                    'R' => *right,
+                    x => panic!("Unexpected direction {x}"),
+                };
+            }
+
+            unreachable!()
+        }
+
+        pub fn is_on_z(&self, step: u64) -> bool {
+            if step < self.cycle_start {
+                self.prefix.contains(&step)
+            } else {
+                let offset = (step - self.cycle_start) % self.period;
+                self.cycle.contains(&(self.cycle_start + offset))
+            }
+        }
+    }
+
+    // the gcd of `a` and `b`, along with `x` and `y` where `a * x + b * y = gcd`
+    fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+        if b == 0 {
+            (a, 1, 0)
+        } else {
+            let (g, x, y) = extended_gcd(b, a % b);
+            (g, y, x - (a / b) * y)
+        }
+    }
+
+    // the `x` with `x ≡ a (mod m)` and `x ≡ b (mod n)`, as a residue of
+    // lcm(m, n), if there is one, the moduli don't need to be coprime
+    pub fn crt((a, m): (u128, u128), (b, n): (u128, u128)) -> Option<(u128, u128)> {
+        let (g, p, _) = extended_gcd(m as i128, n as i128);
+        let diff = b as i128 - a as i128;
+        if diff % g != 0 {
+            return None;
+        }
+
+        let lcm = m / g as u128 * n;
+        let n_g = n as i128 / g;
+        // m * k ≡ b - a (mod n), so k = (b - a) / g * (m / g)⁻¹ (mod n / g)
+        let k = ((diff / g) % n_g * p % n_g + n_g) % n_g;
+        Some(((a + m * k as u128) % lcm, lcm))
+    }
+
+    // The first step where every walk is on a `Z` at once. Before every walk
+    // has reached its cycle the steps are checked one at a time, after that
+    // each walk's cycle is a set of residues, which are combined with the
+    // chinese remainder theorem.
+    pub fn first_simultaneous_arrival(walks: &[Walk]) -> Option<u128> {
+        let settled = walks.iter().map(|walk| walk.cycle_start).max()?;
+
+        if let Some(step) = (1..settled).find(|step| walks.iter().all(|walk| walk.is_on_z(*step))) {
+            return Some(step as u128);
+        }
+
+        let mut residues = BTreeSet::from([(0_u128, 1_u128)]);
+        for walk in walks {
+            residues = residues
+                .iter()
+                .flat_map(|residue| {
+                    walk.cycle.iter().filter_map(|hit| {
+                        crt(
+                            *residue,
+                            (*hit as u128 % walk.period as u128, walk.period as u128),
+                        )
+                    })
+                })
+                .collect();
+        }
+
+        // every walk has settled by `settled`, so the first step at or after it
+        // with the right residue works
+        let settled = settled as u128;
+        residues
+            .into_iter()
+            .map(|(residue, modulus)| {
+                if residue >= settled {
+                    residue
+                } else {
+                    residue + (settled - residue).div_ceil(modulus) * modulus
+                }
+            })
+            .min()
+    }
+
+    pub fn solution(input: String) -> u128 {
+        let (dirs, network) = parse_network(&input);
+
+        let walks = network
+            .keys()
+            .filter(|node| node.0[2] == 'A')
+            .map(|start| Walk::new(&dirs, &network, *start))
+            .collect::<Vec<_>>();
+
+        first_simultaneous_arrival(&walks)
+            .expect("The ghosts are never all on nodes ending in Z at the same time")
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            6
+        );
+    }
+
+    #[test]
+    fn offset_cycles() {
+        let (dirs, network) = parse_network(
+            "L\n\n\
+            AAA = (CCZ, CCZ)\n\
+            CCZ = (DDD, DDD)\n\
+            DDD = (CCZ, CCZ)\n\
+            QQA = (RRR, RRR)\n\
+            RRR = (SSZ, SSZ)\n\
+            SSZ = (TTT, TTT)\n\
+            TTT = (RRR, RRR)\n\
+            MMA = (NNZ, NNZ)\n\
+            NNZ = (OOO, OOO)\n\
+            OOO = (OOO, OOO)\n",
+        );
+        let walk = |start| Walk::new(&dirs, &network, Node::from(start));
+
+        // on a Z at odd steps, and at 2, 5, 8, ...
+        let odd = walk("AAA");
+        let thirds = walk("QQA");
+        assert_eq!(
+            thirds,
+            Walk {
+                prefix: vec![],
+                cycle_start: 1,
+                period: 3,
+                cycle: vec![2],
+            }
+        );
+        assert_eq!(
+            first_simultaneous_arrival(&[odd.clone(), thirds.clone()]),
+            Some(5)
+        );
+
+        // only ever on a Z at step 1, before its cycle
+        let once = walk("MMA");
+        assert_eq!(once.prefix, vec![1]);
+        assert_eq!(
+            first_simultaneous_arrival(&[odd.clone(), once.clone()]),
+            Some(1)
+        );
+        assert_eq!(first_simultaneous_arrival(&[thirds, once]), None);
+
+        assert_eq!(crt((2, 4), (1, 6)), None);
+        assert_eq!(crt((2, 4), (4, 6)), Some((10, 12)));
+    }
+}
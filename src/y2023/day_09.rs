@@ -0,0 +1,224 @@
+pub const DAY_STR: &str = "2023/day_09";
+
+pub use part_1::solution as part_1;
+pub use part_1::solution_streaming as part_1_streaming;
+pub use part_2::solution as part_2;
+pub use part_2::solution_streaming as part_2_streaming;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    for (n, line) in validate::lines(input) {
+        if problems
+            .numbers::<i64>(n, line)
+            .is_some_and(|values| values.is_empty())
+        {
+            problems.at(n, "expected a history of values, found an empty line");
+        }
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+    use std::{fmt::Display, io::BufRead};
+
+    use crate::streaming;
+
+    pub fn vector_diff(pattern: &[i128]) -> Vec<i128> {
+        pattern.windows(2).map(|x| x[1] - x[0]).collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        let x = vec![1, 3, 6, 10, 15, 21];
+        let d = vec![2, 3, 4, 5, 6];
+        assert_eq!(vector_diff(&x), d)
+    }
+
+    pub fn parse_pattern(line: &str) -> Vec<i128> {
+        line.split_ascii_whitespace()
+            .map(|x| x.parse::<i128>().expect("Failed to parse into i128"))
+            .collect::<Vec<_>>()
+    }
+
+    // the differences of a history run out of values before they're all zero,
+    // so no polynomial is known to fit it
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct NeverZero {
+        pub values: usize,
+    }
+
+    impl Display for NeverZero {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "the differences of {} values never reach all zeros",
+                self.values
+            )
+        }
+    }
+
+    // A history as the polynomial that fits it, kept as the first value of each
+    // row of differences (Newton's forward differences), so the value at any
+    // `x` is the sum of `differences[k] * (x choose k)`.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct History {
+        // down to the last row that isn't all zeros
+        pub differences: Vec<i128>,
+        pub len: usize,
+    }
+
+    impl History {
+        pub fn new(values: &[i128]) -> Result<Self, NeverZero> {
+            let mut differences = vec![];
+            let mut row = values.to_vec();
+            while !row.iter().all(|x| *x == 0) {
+                differences.push(row[0]);
+                row = vector_diff(&row);
+            }
+            if row.is_empty() {
+                return Err(NeverZero {
+                    values: values.len(),
+                });
+            }
+
+            Ok(History {
+                differences,
+                len: values.len(),
+            })
+        }
+
+        // the degree of the polynomial, `None` when every value is 0
+        pub fn degree(&self) -> Option<usize> {
+            self.differences.len().checked_sub(1)
+        }
+
+        // the value at `x`, where the history starts at 0, `x` can be before
+        // the start or after the end
+        pub fn value_at(&self, x: i128) -> i128 {
+            let mut choose = 1_i128;
+            let mut value = 0_i128;
+            for (k, difference) in self.differences.iter().enumerate() {
+                if k > 0 {
+                    // (x choose k) = (x choose k - 1) * (x - k + 1) / k, which is
+                    // always exact
+                    choose = choose
+                        .checked_mul(x - k as i128 + 1)
+                        .expect("Extrapolation overflowed i128")
+                        / k as i128;
+                }
+                value = difference
+                    .checked_mul(choose)
+                    .and_then(|term| value.checked_add(term))
+                    .expect("Extrapolation overflowed i128");
+            }
+            value
+        }
+
+        // the value `steps` after the last one
+        pub fn forward(&self, steps: u64) -> i128 {
+            self.value_at(self.len as i128 - 1 + steps as i128)
+        }
+
+        // the value `steps` before the first one
+        pub fn backward(&self, steps: u64) -> i128 {
+            self.value_at(-(steps as i128))
+        }
+    }
+
+    pub fn history(line: &str) -> History {
+        History::new(&parse_pattern(line)).unwrap_or_else(|err| panic!("`{}`: {}", line, err))
+    }
+
+    pub fn solution(input: String) -> i128 {
+        input.lines().map(|line| history(line).forward(1)).sum()
+    }
+
+    pub fn solution_streaming(input: impl BufRead) -> i128 {
+        streaming::lines(input)
+            .map(|line| history(&line).forward(1))
+            .sum()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            114
+        );
+    }
+
+    #[test]
+    fn streaming_sample() {
+        let file =
+            std::fs::File::open(format!("inputs/{}/part_1/sample.txt", super::DAY_STR)).unwrap();
+        assert_eq!(solution_streaming(std::io::BufReader::new(file)), 114);
+    }
+
+    #[test]
+    fn extrapolation() {
+        let squares = History::new(&[1, 4, 9, 16]).unwrap();
+        assert_eq!(squares.degree(), Some(2));
+        assert_eq!(squares.forward(1), 25);
+        assert_eq!(squares.forward(96), 100 * 100);
+        assert_eq!(squares.backward(1), 0);
+        assert_eq!(squares.backward(3), 4);
+
+        assert_eq!(History::new(&[7, 7]).unwrap().degree(), Some(0));
+        assert_eq!(History::new(&[0, 0, 0]).unwrap().degree(), None);
+        assert_eq!(History::new(&[0, 0, 0]).unwrap().forward(5), 0);
+
+        // too far to step through one at a time
+        let cubes = History::new(&[0, 1, 8, 27, 64]).unwrap();
+        assert_eq!(cubes.forward(999_999_996), 1_000_000_000_i128.pow(3));
+
+        assert_eq!(History::new(&[1, 2, 4, 8]), Err(NeverZero { values: 4 }));
+        assert_eq!(History::new(&[]), Err(NeverZero { values: 0 }));
+    }
+}
+
+mod part_2 {
+
+    use std::io::BufRead;
+
+    use super::part_1::history;
+
+    use crate::streaming;
+
+    pub fn solution(input: String) -> i128 {
+        input.lines().map(|line| history(line).backward(1)).sum()
+    }
+
+    pub fn solution_streaming(input: impl BufRead) -> i128 {
+        streaming::lines(input)
+            .map(|line| history(&line).backward(1))
+            .sum()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn streaming_sample() {
+        let file =
+            std::fs::File::open(format!("inputs/{}/part_2/sample.txt", super::DAY_STR)).unwrap();
+        assert_eq!(solution_streaming(std::io::BufReader::new(file)), 2);
+    }
+}
@@ -0,0 +1,469 @@
+pub const DAY_STR: &str = "2023/day_10";
+
+pub use part_1::solution as part_1;
+pub use part_2::solution as part_2;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let lines = validate::lines(input).collect::<Vec<_>>();
+    validate::grid(&mut problems, &lines, "|-LJ7F.S");
+    validate::exactly_one(&mut problems, input, 'S');
+
+    problems.into_vec()
+}
+
+mod part_1 {
+    use std::fmt::{Debug, Display, Write};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct GridPos {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Dir {
+        North,
+        South,
+        West,
+        East,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Pipe {
+        Vertical,
+        Horizontal,
+        TurnNE,
+        TurnNW,
+        TurnSE,
+        TurnSW,
+        Ground,
+        Start,
+    }
+
+    pub struct Grid {
+        pub vec2d: Vec<Vec<Pipe>>,
+        pub start: GridPos,
+    }
+
+    impl Dir {
+        fn offset(&self) -> GridPos {
+            use Dir::*;
+            match &self {
+                North => GridPos { x: 0, y: -1 },
+                South => GridPos { x: 0, y: 1 },
+                West => GridPos { x: -1, y: 0 },
+                East => GridPos { x: 1, y: 0 },
+            }
+        }
+
+        fn reverse(self) -> Dir {
+            match self {
+                Dir::North => Dir::South,
+                Dir::South => Dir::North,
+                Dir::West => Dir::East,
+                Dir::East => Dir::West,
+            }
+        }
+    }
+
+    impl GridPos {
+        fn moved(self, dir: Dir) -> GridPos {
+            let offset = dir.offset();
+            GridPos {
+                x: self.x + offset.x,
+                y: self.y + offset.y,
+            }
+        }
+
+        fn try_as_usize_tuple(self) -> Option<(usize, usize)> {
+            Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+        }
+    }
+
+    impl Pipe {
+        fn connections(self) -> Option<(Dir, Dir)> {
+            use Dir::*;
+            use Pipe::*;
+            match self {
+                Vertical => Some((North, South)),
+                Horizontal => Some((West, East)),
+                TurnNE => Some((North, East)),
+                TurnNW => Some((North, West)),
+                TurnSE => Some((South, East)),
+                TurnSW => Some((South, West)),
+                Ground => None,
+                Start => None,
+            }
+        }
+
+        fn get_other_dir(self, dir: Dir) -> Dir {
+            match dir {
+                Dir::North => match self {
+                    Pipe::Vertical => Dir::South,
+                    Pipe::TurnNE => Dir::East,
+                    Pipe::TurnNW => Dir::West,
+                    _ => panic!(),
+                },
+                Dir::South => match self {
+                    Pipe::Vertical => Dir::North,
+                    Pipe::TurnSE => Dir::East,
+                    Pipe::TurnSW => Dir::West,
+                    _ => panic!(),
+                },
+                Dir::West => match self {
+                    Pipe::Horizontal => Dir::East,
+                    Pipe::TurnNW => Dir::North,
+                    Pipe::TurnSW => Dir::South,
+                    _ => panic!(),
+                },
+                Dir::East => match self {
+                    Pipe::Horizontal => Dir::West,
+                    Pipe::TurnNE => Dir::North,
+                    Pipe::TurnSE => Dir::South,
+                    _ => panic!(),
+                },
+            }
+        }
+
+        // dir is the direction they are entering the pipe from
+        // this is opposite to the direction relative to the pipe
+        fn get_next_dir(self, dir: Dir) -> Dir {
+            self.get_other_dir(dir.reverse())
+        }
+    }
+
+    impl From<char> for Pipe {
+        fn from(value: char) -> Self {
+            use Pipe::*;
+            match value {
+                'S' => Start,
+                '.' => Ground,
+                '-' => Horizontal,
+                '|' => Vertical,
+                'L' => TurnNE,
+                'J' => TurnNW,
+                'F' => TurnSE,
+                '7' => TurnSW,
+                x => panic!("Invalid char `{x}` found. Cannot parse into `Pipe`"),
+            }
+        }
+    }
+
+    impl From<Pipe> for char {
+        fn from(value: Pipe) -> Self {
+            use Pipe::*;
+            match value {
+                Start => 'S',
+                Ground => '.',
+                Horizontal => '-',
+                Vertical => '|',
+                TurnNE => 'L',
+                TurnNW => 'J',
+                TurnSE => 'F',
+                TurnSW => '7',
+            }
+        }
+    }
+
+    impl Grid {
+        pub fn new(vec2d: Vec<Vec<Pipe>>) -> Grid {
+            let start = vec2d
+                .iter()
+                .enumerate()
+                .find_map(|(y, row)| {
+                    row.iter().enumerate().find_map(|(x, pipe)| match pipe {
+                        Pipe::Start => Some(GridPos {
+                            x: x as i32,
+                            y: y as i32,
+                        }),
+                        _ => None,
+                    })
+                })
+                .expect("There is no start tile `S`");
+
+            Grid { vec2d, start }
+        }
+    }
+
+    impl Debug for Grid {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("Grid { ")?;
+            for row in &self.vec2d {
+                f.write_str("\n\t")?;
+                for pipe in row {
+                    f.write_char(char::from(*pipe))?;
+                }
+            }
+            f.write_fmt(format_args!(
+                "\n}} start: {{ x : {} , y : {} }}\n",
+                self.start.x, self.start.y
+            ))
+        }
+    }
+
+    impl Grid {
+        pub fn get_pipe(&self, grid_pos: GridPos) -> Pipe {
+            grid_pos
+                .try_as_usize_tuple()
+                .map(|(ix, iy)| self.vec2d[iy][ix])
+                .expect("GridPos was invalid")
+        }
+
+        pub fn get_pipe_ref_mut(&mut self, grid_pos: GridPos) -> &mut Pipe {
+            grid_pos
+                .try_as_usize_tuple()
+                .map(|(ix, iy)| &mut self.vec2d[iy][ix])
+                .expect("GridPos was invalid")
+        }
+
+        pub fn walk(&self, grid_pos: GridPos, last_dir: Dir) -> (Dir, GridPos) {
+            let move_dir = self.get_pipe(grid_pos).get_next_dir(last_dir);
+            (move_dir, grid_pos.moved(move_dir))
+        }
+
+        // the pipe at `grid_pos`, or `None` if that's off the grid
+        pub fn pipe_at(&self, grid_pos: GridPos) -> Option<Pipe> {
+            let (ix, iy) = grid_pos.try_as_usize_tuple()?;
+            self.vec2d.get(iy)?.get(ix).copied()
+        }
+
+        // the tiles of the loop if the start were `start_pipe`, or `None` if
+        // following the pipes from it doesn't come back round
+        fn trace(&self, start_pipe: Pipe) -> Option<Vec<GridPos>> {
+            let (first, last) = start_pipe.connections()?;
+            let mut tiles = vec![self.start];
+            let (mut pos, mut dir) = (self.start, first);
+
+            // each pipe only connects two ways, so the walk can't get stuck
+            // going round some other loop, it either ends or gets back here
+            loop {
+                pos = pos.moved(dir);
+                if pos == self.start {
+                    return (dir.reverse() == last).then_some(tiles);
+                }
+
+                let (a, b) = self.pipe_at(pos)?.connections()?;
+                let entered_from = dir.reverse();
+                dir = if a == entered_from {
+                    b
+                } else if b == entered_from {
+                    a
+                } else {
+                    return None;
+                };
+                tiles.push(pos);
+            }
+        }
+
+        // the loop through the start, trying every shape the start could be and
+        // keeping the longest loop, pipes that aren't on it are ignored
+        pub fn find_loop(&self) -> Result<PipeLoop, NoLoop> {
+            use Pipe::*;
+            [Vertical, Horizontal, TurnNE, TurnNW, TurnSE, TurnSW]
+                .into_iter()
+                .filter_map(|start_pipe| {
+                    self.trace(start_pipe)
+                        .map(|tiles| PipeLoop { start_pipe, tiles })
+                })
+                .max_by_key(|pipe_loop| pipe_loop.tiles.len())
+                .ok_or(NoLoop { start: self.start })
+        }
+    }
+
+    // no shape of the start pipe gives a closed loop
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct NoLoop {
+        pub start: GridPos,
+    }
+
+    impl Display for NoLoop {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "no closed loop goes through the start at x: {}, y: {}",
+                self.start.x, self.start.y
+            )
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct PipeLoop {
+        // the pipe hidden under the start tile
+        pub start_pipe: Pipe,
+        // every tile on the loop in order, beginning with the start
+        pub tiles: Vec<GridPos>,
+    }
+
+    impl PipeLoop {
+        pub fn len(&self) -> usize {
+            self.tiles.len()
+        }
+
+        // how many steps it is to the tile farthest from the start
+        pub fn farthest(&self) -> usize {
+            self.len() / 2
+        }
+
+        pub fn farthest_tile(&self) -> GridPos {
+            self.tiles[self.farthest()]
+        }
+
+        // the number of tiles inside the loop, from its area by the shoelace
+        // formula and Pick's theorem, A = i + b / 2 - 1
+        pub fn enclosed(&self) -> usize {
+            let twice_area = self
+                .tiles
+                .iter()
+                .zip(self.tiles.iter().cycle().skip(1))
+                .map(|(a, b)| a.x as i64 * b.y as i64 - b.x as i64 * a.y as i64)
+                .sum::<i64>()
+                .unsigned_abs() as usize;
+
+            (twice_area - self.len()) / 2 + 1
+        }
+    }
+
+    pub fn parse_grid(input: &str) -> Grid {
+        let pipes = input
+            .lines()
+            .map(|line| line.chars().map(Pipe::from).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        Grid::new(pipes)
+    }
+
+    pub fn find_loop(input: &str) -> PipeLoop {
+        parse_grid(input)
+            .find_loop()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn solution(input: String) -> usize {
+        find_loop(&input).farthest()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            4
+        );
+    }
+
+    #[test]
+    fn sample2() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample2.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            8
+        );
+    }
+
+    #[test]
+    fn ambiguous_start() {
+        // the start connects to pipes on every side, but only as a `F` does it
+        // make a loop
+        let grid = parse_grid(".|...\n-S-7.\n.|.|.\n.L-J.\n");
+        let pipe_loop = grid.find_loop().unwrap();
+        assert_eq!(pipe_loop.start_pipe, Pipe::TurnSE);
+        assert_eq!(pipe_loop.len(), 8);
+        assert_eq!(pipe_loop.farthest(), 4);
+        assert_eq!(pipe_loop.farthest_tile(), GridPos { x: 3, y: 3 });
+        assert_eq!(pipe_loop.enclosed(), 1);
+
+        let grid = parse_grid("S-.\n|..\n");
+        assert_eq!(
+            grid.find_loop(),
+            Err(NoLoop {
+                start: GridPos { x: 0, y: 0 }
+            })
+        );
+    }
+}
+
+mod part_2 {
+
+    use super::part_1::find_loop;
+
+    pub fn solution(input: String) -> usize {
+        find_loop(&input).enclosed()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn sample2() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample2.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn sample3() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample3.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            4
+        );
+    }
+
+    #[test]
+    fn sample4() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample4.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            4
+        );
+    }
+
+    #[test]
+    fn sample5() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample5.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            8
+        );
+    }
+
+    #[test]
+    fn sample6() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample6.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            10
+        );
+    }
+}
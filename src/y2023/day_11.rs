@@ -0,0 +1,180 @@
+pub const DAY_STR: &str = "2023/day_11";
+
+pub use part_1::solution as part_1;
+pub use part_2::solution as part_2;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let lines = validate::lines(input).collect::<Vec<_>>();
+    validate::grid(&mut problems, &lines, ".#");
+
+    problems.into_vec()
+}
+
+mod part_1 {
+
+    use itertools::Itertools;
+
+    // The galaxies in an image of the universe, along with the rows and
+    // columns that have none, which grow by whatever expansion factor is asked
+    // for. An expansion factor of 2 turns each empty row into two.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Universe {
+        // (x, y) of each galaxy before expanding, in reading order
+        pub galaxies: Vec<(u64, u64)>,
+        // sorted
+        empty_columns: Vec<u64>,
+        empty_rows: Vec<u64>,
+    }
+
+    impl Universe {
+        pub fn new(input: &str) -> Self {
+            let mut galaxies = vec![];
+            let mut width = 0;
+            let mut height = 0;
+            for (y, row) in input.lines().enumerate() {
+                for (x, c) in row.chars().enumerate() {
+                    match c {
+                        '.' => {}
+                        '#' => galaxies.push((x as u64, y as u64)),
+                        _ => panic!("Unexpected character `{c}` in the universe"),
+                    }
+                    width = width.max(x as u64 + 1);
+                }
+                height = y as u64 + 1;
+            }
+
+            let empty = |size: u64, used: Vec<u64>| {
+                (0..size)
+                    .filter(|i| used.binary_search(i).is_err())
+                    .collect_vec()
+            };
+            let columns = galaxies.iter().map(|g| g.0).sorted().dedup().collect();
+            let rows = galaxies.iter().map(|g| g.1).sorted().dedup().collect();
+
+            Universe {
+                empty_columns: empty(width, columns),
+                empty_rows: empty(height, rows),
+                galaxies,
+            }
+        }
+
+        // where `galaxy` ends up once the universe has expanded, in u128 so any
+        // factor fits
+        pub fn expanded(&self, galaxy: usize, expansion_factor: u64) -> (u128, u128) {
+            assert!(
+                expansion_factor > 0,
+                "The expansion factor must be at least 1"
+            );
+            let (x, y) = self.galaxies[galaxy];
+            let grow = |empty: &[u64], at: u64| {
+                at as u128
+                    + empty.partition_point(|e| *e < at) as u128 * (expansion_factor as u128 - 1)
+            };
+            (grow(&self.empty_columns, x), grow(&self.empty_rows, y))
+        }
+
+        // the distance between two galaxies, numbered from 0 in reading order
+        pub fn distance(&self, a: usize, b: usize, expansion_factor: u64) -> u128 {
+            let (ax, ay) = self.expanded(a, expansion_factor);
+            let (bx, by) = self.expanded(b, expansion_factor);
+            ax.abs_diff(bx) + ay.abs_diff(by)
+        }
+
+        // The sum of the distances between every pair of galaxies. The distance
+        // along each axis adds up separately, and once an axis is sorted the
+        // galaxy at index `i` is after `i` others, so it adds
+        // `i * coordinate - (sum of the coordinates before it)`.
+        pub fn total_distance(&self, expansion_factor: u64) -> u128 {
+            let expanded = (0..self.galaxies.len())
+                .map(|galaxy| self.expanded(galaxy, expansion_factor))
+                .collect_vec();
+
+            let axis = |coordinates: Vec<u128>| {
+                let mut before = 0_u128;
+                coordinates
+                    .into_iter()
+                    .sorted()
+                    .enumerate()
+                    .map(|(i, coordinate)| {
+                        let distance = i as u128 * coordinate - before;
+                        before += coordinate;
+                        distance
+                    })
+                    .sum::<u128>()
+            };
+
+            axis(expanded.iter().map(|g| g.0).collect())
+                + axis(expanded.iter().map(|g| g.1).collect())
+        }
+    }
+
+    pub fn solution(input: String) -> u128 {
+        Universe::new(&input).total_distance(2)
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            374
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let universe = Universe::new(
+            &std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                .unwrap(),
+        );
+
+        // the pairs from the puzzle, numbered from 1 there
+        assert_eq!(universe.distance(4, 8, 2), 9);
+        assert_eq!(universe.distance(0, 6, 2), 15);
+        assert_eq!(universe.distance(2, 5, 2), 17);
+        assert_eq!(universe.distance(7, 8, 2), 5);
+
+        for factor in [1, 2, 10, 100, 1_000_000] {
+            let pairwise = (0..universe.galaxies.len())
+                .tuple_combinations()
+                .map(|(a, b)| universe.distance(a, b, factor))
+                .sum::<u128>();
+            assert_eq!(universe.total_distance(factor), pairwise);
+        }
+        assert_eq!(universe.total_distance(10), 1030);
+        assert_eq!(universe.total_distance(100), 8410);
+        assert_eq!(
+            universe.distance(7, 8, u64::MAX),
+            4 + (u64::MAX as u128 - 1)
+        );
+    }
+}
+
+mod part_2 {
+
+    use super::part_1::Universe;
+
+    pub fn solution(input: String) -> u128 {
+        Universe::new(&input).total_distance(1_000_000)
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            82000210
+        );
+    }
+}
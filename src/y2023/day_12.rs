@@ -0,0 +1,465 @@
+pub const DAY_STR: &str = "2023/day_12";
+
+pub use part_1::solution as part_1;
+pub use part_2::solution as part_2;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    for (n, line) in validate::lines(input) {
+        let Some((springs, groups)) = line.split_once(' ') else {
+            problems.at(n, "expected springs and group sizes separated by a space");
+            continue;
+        };
+        if let Some(c) = springs.chars().find(|c| !".#?".contains(*c)) {
+            problems.at(
+                n,
+                format!("unexpected spring `{}`, expected one of `.#?`", c),
+            );
+        }
+        for group in groups.split(',') {
+            problems.number::<usize>(n, group);
+        }
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+    use std::fmt::Debug;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Spring {
+        Operational,
+        Damaged,
+        Unknown,
+    }
+
+    pub struct DBGWrap<'a>(pub &'a [Spring]);
+    impl<'a> Debug for DBGWrap<'a> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let data = self.0.iter().map(|s| char::from(*s)).collect::<String>();
+            f.write_str(&data)
+        }
+    }
+
+    impl From<char> for Spring {
+        fn from(value: char) -> Self {
+            match value {
+                '.' => Self::Operational,
+                '#' => Self::Damaged,
+                '?' => Self::Unknown,
+                _x => panic!("Encounter unexpected character `{_x}`"),
+            }
+        }
+    }
+
+    impl From<Spring> for char {
+        fn from(value: Spring) -> Self {
+            match value {
+                Spring::Operational => '.',
+                Spring::Damaged => '#',
+                Spring::Unknown => '?',
+            }
+        }
+    }
+
+    // checks if `length` of damaged springs can be place
+    // at index **1** of the `map` slice.
+    // requires a slice of at least size `length + 2`
+    pub fn is_placeable(map: &[Spring], length: usize) -> bool {
+        if map.len() < length + 2 {
+            return false;
+        }
+
+        if map[0] == Spring::Damaged {
+            return false;
+        }
+
+        if (1..=length).any(|i| map[i] == Spring::Operational) {
+            return false;
+        }
+
+        if map[length + 1] == Spring::Damaged {
+            return false;
+        }
+
+        true
+    }
+
+    pub fn arrangements(map: &mut [Spring], list: &[usize]) -> usize {
+        // dbg!("new func");
+
+        let mut num_arrangements = 0;
+        let length = list[0];
+
+        for start_idx in 0..map.len() {
+            let partial = &map[start_idx..];
+            let before = &map[..start_idx];
+            // dbg!(DBGWrap(before));
+
+            if before.len() > 1 && before.last().unwrap() == &Spring::Damaged {
+                // dbg!("break");
+                break;
+            }
+
+            // dbg!(DBGWrap(partial));
+
+            let res = is_placeable(partial, length);
+            // dbg!(res);
+
+            if res {
+                if list.len() > 1 {
+                    let sub_map = &mut map[start_idx + length..];
+
+                    let og_val = sub_map[0];
+                    sub_map[0] = Spring::Damaged;
+
+                    // dbg!("after placement");
+                    // dbg!(DBGWrap(sub_map));
+                    num_arrangements += arrangements(sub_map, &list[1..]);
+
+                    sub_map[0] = og_val;
+
+                    // dbg!(num_arrangements);
+                } else {
+                    // check if the second half has only Operational left
+
+                    // dbg!(DBGWrap(partial));
+                    let remaining = &partial[length + 1..];
+                    // dbg!(DBGWrap(remaining));
+
+                    if !remaining.iter().any(|s| s == &Spring::Damaged) {
+                        num_arrangements += 1;
+                        // dbg!(num_arrangements);
+                    }
+
+                    // todo!()
+                }
+            }
+        }
+        // dbg!(num_arrangements);
+        // todo!();
+        num_arrangements
+    }
+
+    #[test]
+    fn test_arrangements_1() {
+        use Spring::Damaged as D;
+        use Spring::Operational as O;
+        use Spring::Unknown as U;
+        assert_eq!(
+            arrangements(&mut [O, U, U, U, O, D, D, D, O], &[1, 1, 3]),
+            1
+        );
+    }
+
+    #[test]
+    fn test_arrangements_2() {
+        use Spring::Damaged as D;
+        use Spring::Operational as O;
+        use Spring::Unknown as U;
+
+        assert_eq!(
+            arrangements(&mut [O, U, U, U, O, D, O, D, O], &[1, 1, 1]),
+            3
+        );
+    }
+
+    // A row of springs and the sizes of its groups of damaged springs, which
+    // can be unfolded any number of times.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Row {
+        pub springs: Vec<Spring>,
+        pub groups: Vec<usize>,
+    }
+
+    impl Row {
+        pub fn new(line: &str) -> Self {
+            let (springs, groups) = line
+                .split_once(' ')
+                .unwrap_or_else(|| panic!("Expected springs and groups in `{line}`"));
+            Row {
+                springs: springs.chars().map(Spring::from).collect(),
+                groups: groups
+                    .split_terminator(',')
+                    .map(|s| s.parse::<usize>().expect("Failed to parse into `usize`"))
+                    .collect(),
+            }
+        }
+
+        // `factor` copies of the springs joined by unknown springs, and
+        // `factor` copies of the groups
+        pub fn unfolded(&self, factor: usize) -> Self {
+            assert!(factor > 0, "The unfolding factor must be at least 1");
+            let mut springs = self.springs.clone();
+            for _ in 1..factor {
+                springs.push(Spring::Unknown);
+                springs.extend_from_slice(&self.springs);
+            }
+            Row {
+                springs,
+                groups: self.groups.repeat(factor),
+            }
+        }
+
+        // whether group `group` fits at the start of `springs[at..]`, followed
+        // by the end of the row or a spring that can be operational
+        fn fits(&self, at: usize, group: usize, operational_before: &[usize]) -> bool {
+            let size = self.groups[group];
+            let end = at + size;
+            end <= self.springs.len()
+                && operational_before[end] == operational_before[at]
+                && self.springs.get(end) != Some(&Spring::Damaged)
+        }
+
+        // `table[i][j]` is the number of ways to arrange `springs[i..]` with
+        // `groups[j..]`, filled in from the end of the row. It's `None` when
+        // that doesn't fit in u128, which can happen for states the start never
+        // reaches, and for the others only if the whole row has that many.
+        fn table(&self) -> (Vec<Vec<Option<u128>>>, Vec<usize>) {
+            let springs = self.springs.len();
+            let groups = self.groups.len();
+
+            let mut operational_before = vec![0; springs + 1];
+            for (i, spring) in self.springs.iter().enumerate() {
+                operational_before[i + 1] =
+                    operational_before[i] + (*spring == Spring::Operational) as usize;
+            }
+
+            let mut table = vec![vec![Some(0_u128); groups + 1]; springs + 1];
+            table[springs][groups] = Some(1);
+            for i in (0..springs).rev() {
+                for j in 0..=groups {
+                    let mut ways = Some(0_u128);
+                    if self.springs[i] != Spring::Damaged {
+                        ways = table[i + 1][j];
+                    }
+                    if self.springs[i] != Spring::Operational
+                        && j < groups
+                        && self.fits(i, j, &operational_before)
+                    {
+                        let next = (i + self.groups[j] + 1).min(springs);
+                        ways = ways
+                            .zip(table[next][j + 1])
+                            .and_then(|(a, b)| a.checked_add(b));
+                    }
+                    table[i][j] = ways;
+                }
+            }
+            (table, operational_before)
+        }
+
+        pub fn count(&self) -> u128 {
+            self.table().0[0][0].expect("Arrangements overflowed u128")
+        }
+
+        // every concrete arrangement of the row, found one at a time and
+        // without running into dead ends by following the counting table
+        pub fn arrangements(&self) -> Arrangements<'_> {
+            let (table, operational_before) = self.table();
+            let stack = if table[0][0] != Some(0) {
+                vec![(0, 0, vec![])]
+            } else {
+                vec![]
+            };
+            Arrangements {
+                row: self,
+                table,
+                operational_before,
+                stack,
+            }
+        }
+    }
+
+    pub struct Arrangements<'a> {
+        row: &'a Row,
+        table: Vec<Vec<Option<u128>>>,
+        operational_before: Vec<usize>,
+        // (spring, group, springs placed so far), only for states with at
+        // least one arrangement
+        stack: Vec<(usize, usize, Vec<Spring>)>,
+    }
+
+    impl Iterator for Arrangements<'_> {
+        type Item = Vec<Spring>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let springs = self.row.springs.len();
+            while let Some((i, j, placed)) = self.stack.pop() {
+                if i == springs {
+                    return Some(placed);
+                }
+
+                // pushed first so a damaged group is tried before skipping
+                if self.row.springs[i] != Spring::Damaged && self.table[i + 1][j] != Some(0) {
+                    let mut placed = placed.clone();
+                    placed.push(Spring::Operational);
+                    self.stack.push((i + 1, j, placed));
+                }
+                if self.row.springs[i] != Spring::Operational
+                    && j < self.row.groups.len()
+                    && self.row.fits(i, j, &self.operational_before)
+                {
+                    let size = self.row.groups[j];
+                    let next = (i + size + 1).min(springs);
+                    if self.table[next][j + 1] != Some(0) {
+                        let mut placed = placed;
+                        placed.extend(std::iter::repeat_n(Spring::Damaged, size));
+                        if next > i + size {
+                            placed.push(Spring::Operational);
+                        }
+                        self.stack.push((next, j + 1, placed));
+                    }
+                }
+            }
+            None
+        }
+    }
+
+    pub fn solution(input: String) -> u128 {
+        input.lines().map(|line| Row::new(line).count()).sum()
+    }
+
+    #[test]
+    fn arrangements_of_a_row() {
+        let row = Row::new("?###???????? 3,2,1");
+        let arrangements = row
+            .arrangements()
+            .map(|springs| springs.into_iter().map(char::from).collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(arrangements.len() as u128, row.count());
+        assert_eq!(arrangements[0], ".###.##.#...");
+        assert_eq!(arrangements[9], ".###....##.#");
+        assert!(arrangements.iter().all(|springs| !springs.contains('?')));
+
+        assert_eq!(Row::new("# 2").count(), 0);
+        assert_eq!(Row::new("# 2").arrangements().next(), None);
+        assert_eq!(Row::new("??? ").count(), 1);
+        assert_eq!(Row::new("???.### 1,1,3").unfolded(5).count(), 1);
+        assert_eq!(Row::new("?###???????? 3,2,1").unfolded(5).count(), 506250);
+
+        // too many to have counted one at a time, 120 choose 60
+        assert_eq!(
+            Row::new("?? 1").unfolded(60).count(),
+            96614908840363322603893139521372656
+        );
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            21
+        );
+    }
+}
+
+mod part_2 {
+
+    use super::part_1::*;
+
+    // counts the arrangements of an already unfolded row, `map` is padded with
+    // an operational spring at each end like part 1's
+    pub fn arrangements(map: Vec<Spring>, list: Vec<usize>) -> u128 {
+        Row {
+            springs: map,
+            groups: list,
+        }
+        .count()
+    }
+
+    #[test]
+    fn test_arrangements_1() {
+        // use Spring::Damaged as D;
+        // use Spring::Operational as O;
+        // use Spring::Unknown as U;
+
+        let x = ".???.###????.###????.###????.###????.###."
+            .chars()
+            .map(Spring::from)
+            .collect::<Vec<_>>();
+
+        let y = vec![1, 1, 3, 1, 1, 3, 1, 1, 3, 1, 1, 3, 1, 1, 3];
+
+        assert_eq!(arrangements(x, y), 1);
+    }
+
+    #[test]
+    fn test_arrangements_2() {
+        // use Spring::Damaged as D;
+        // use Spring::Operational as O;
+        // use Spring::Unknown as U;
+
+        let x = "..??..??...?##.?.??..??...?##.?.??..??...?##.?.??..??...?##.?.??..??...?##.."
+            .chars()
+            .map(Spring::from)
+            .collect::<Vec<_>>();
+
+        let y = vec![1, 1, 3, 1, 1, 3, 1, 1, 3, 1, 1, 3, 1, 1, 3];
+
+        assert_eq!(arrangements(x, y), 16384);
+    }
+
+    pub fn solution(input: String) -> u128 {
+        input
+            .lines()
+            .map(|line| Row::new(line).unfolded(5).count())
+            .sum()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            525152
+        );
+    }
+
+    #[cfg(test)]
+    mod differential {
+        use std::iter;
+
+        use proptest::prelude::*;
+
+        use super::super::part_1;
+        use super::*;
+
+        fn spring() -> impl Strategy<Value = Spring> {
+            prop_oneof![
+                Just(Spring::Operational),
+                Just(Spring::Damaged),
+                Just(Spring::Unknown),
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn matches_brute_force(
+                springs in prop::collection::vec(spring(), 1..14),
+                list in prop::collection::vec(1_usize..5, 1..5),
+            ) {
+                let mut map = iter::once(Spring::Operational)
+                    .chain(springs)
+                    .chain(iter::once(Spring::Operational))
+                    .collect::<Vec<_>>();
+
+                prop_assert_eq!(
+                    arrangements(map.clone(), list.clone()),
+                    part_1::arrangements(&mut map, &list) as u128
+                );
+            }
+        }
+    }
+}
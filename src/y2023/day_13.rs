@@ -0,0 +1,258 @@
+pub const DAY_STR: &str = "2023/day_13";
+
+pub use part_1::solution as part_1;
+pub use part_1::solution_explained as part_1_explained;
+pub use part_2::solution as part_2;
+pub use part_2::solution_explained as part_2_explained;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    for pattern in validate::blocks(input) {
+        validate::grid(&mut problems, &pattern, ".#");
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+
+    use std::cmp::min;
+
+    use itertools::Itertools;
+
+    use crate::explain;
+    use crate::explain::{Explain, Silent};
+
+    // An image with each row and each column as a bitmask of its rocks, bit `i`
+    // of a row is column `i` and bit `i` of a column is row `i`.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Image {
+        pub rows: Vec<u64>,
+        pub columns: Vec<u64>,
+    }
+
+    impl Image {
+        pub fn new(pattern: &str) -> Self {
+            let lines = pattern.lines().collect_vec();
+            let width = lines.first().map_or(0, |line| line.len());
+            assert!(
+                lines.len() <= 64 && width <= 64,
+                "Images are at most 64 by 64, found {} by {}",
+                width,
+                lines.len()
+            );
+
+            let mut rows = vec![0_u64; lines.len()];
+            let mut columns = vec![0_u64; width];
+            for (y, line) in lines.iter().enumerate() {
+                for (x, c) in line.chars().enumerate() {
+                    match c {
+                        '.' => {}
+                        '#' => {
+                            rows[y] |= 1 << x;
+                            columns[x] |= 1 << y;
+                        }
+                        _ => panic!("Unexpected character `{c}` in an image"),
+                    }
+                }
+            }
+            Image { rows, columns }
+        }
+
+        pub fn height(&self) -> usize {
+            self.rows.len()
+        }
+
+        pub fn width(&self) -> usize {
+            self.columns.len()
+        }
+
+        // every line the image could be reflected along, vertical ones first,
+        // with how many tiles don't match their reflection
+        pub fn reflections(&self) -> Vec<Reflection> {
+            let along = |axis: Axis, lines: &[u64]| {
+                (0..lines.len().saturating_sub(1))
+                    .map(|idx| Reflection {
+                        axis,
+                        after: idx + 1,
+                        defects: get_mirrored_indices(idx, lines.len())
+                            .map(|(l, r)| (lines[l] ^ lines[r]).count_ones())
+                            .sum(),
+                    })
+                    .collect_vec()
+            };
+            let mut reflections = along(Axis::Vertical, &self.columns);
+            reflections.extend(along(Axis::Horizontal, &self.rows));
+            reflections
+        }
+
+        // the first reflection once exactly `smudges` tiles are fixed
+        pub fn reflection(&self, smudges: u32) -> Option<Reflection> {
+            self.reflections()
+                .into_iter()
+                .find(|reflection| reflection.defects == smudges)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Axis {
+        // between two columns
+        Vertical,
+        // between two rows
+        Horizontal,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Reflection {
+        pub axis: Axis,
+        // the number of columns left of the line, or rows above it
+        pub after: usize,
+        pub defects: u32,
+    }
+
+    impl Reflection {
+        pub fn score(&self) -> usize {
+            match self.axis {
+                Axis::Vertical => self.after,
+                Axis::Horizontal => self.after * 100,
+            }
+        }
+    }
+
+    fn get_mirrored_indices(idx: usize, size: usize) -> impl Iterator<Item = (usize, usize)> {
+        let left_size = idx + 1;
+        let right_size = size - idx - 1;
+
+        let to_take = min(left_size, right_size);
+
+        let left = (0..idx + 1).rev().take(to_take);
+        let right = (idx + 1..size).take(to_take);
+
+        left.zip(right)
+    }
+
+    #[test]
+    fn mirrored_idx() {
+        assert_eq!(
+            get_mirrored_indices(4, 9).collect_vec(),
+            vec![(4, 5), (3, 6), (2, 7), (1, 8)]
+        );
+        assert_eq!(
+            get_mirrored_indices(2, 9).collect_vec(),
+            vec![(2, 3), (1, 4), (0, 5)]
+        );
+    }
+
+    // the sum of the scores of each image's reflection once exactly `smudges`
+    // of its tiles are fixed
+    pub fn summarize(input: &str, smudges: u32, explain: &mut dyn Explain) -> usize {
+        let fixed = match smudges {
+            0 => String::new(),
+            1 => " once the smudge is fixed".to_string(),
+            k => format!(" once the {} smudges are fixed", k),
+        };
+
+        input
+            .split_terminator("\n\n")
+            .map(Image::new)
+            .enumerate()
+            .map(|(n, image)| {
+                let reflection = image.reflection(smudges).unwrap_or_else(|| {
+                    panic!("Image {} has no reflection with {} smudges", n + 1, smudges)
+                });
+                let (axis, line) = match reflection.axis {
+                    Axis::Vertical => ("vertical", "column"),
+                    Axis::Horizontal => ("horizontal", "row"),
+                };
+                explain!(
+                    explain,
+                    "Image {}: {} mirror after {} {}{}, scores {}",
+                    n + 1,
+                    axis,
+                    line,
+                    reflection.after,
+                    fixed,
+                    reflection.score()
+                );
+                reflection.score()
+            })
+            .sum()
+    }
+
+    pub fn solution(input: String) -> usize {
+        solution_explained(input, &mut Silent)
+    }
+
+    pub fn solution_explained(input: String, explain: &mut dyn Explain) -> usize {
+        summarize(&input, 0, explain)
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            405
+        );
+    }
+
+    #[test]
+    fn reflections() {
+        let input = std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+            .unwrap();
+        let image = Image::new(input.split("\n\n").next().unwrap());
+        assert_eq!((image.width(), image.height()), (9, 7));
+
+        let reflections = image.reflections();
+        assert_eq!(reflections.len(), 8 + 6);
+        assert_eq!(
+            image.reflection(0),
+            Some(Reflection {
+                axis: Axis::Vertical,
+                after: 5,
+                defects: 0
+            })
+        );
+        assert_eq!(image.reflection(1).map(|r| r.score()), Some(300));
+        assert_eq!(reflections.iter().map(|r| r.defects).min(), Some(0),);
+
+        // more smudges than parts 1 and 2 ever fix
+        assert_eq!(summarize("#.\n..", 1, &mut Silent), 1);
+        assert_eq!(summarize("#.\n.#", 2, &mut Silent), 1);
+        assert_eq!(Image::new("#.\n.#").reflection(3), None);
+    }
+}
+
+mod part_2 {
+
+    use super::part_1::summarize;
+
+    use crate::explain::{Explain, Silent};
+
+    pub fn solution(input: String) -> usize {
+        solution_explained(input, &mut Silent)
+    }
+
+    pub fn solution_explained(input: String, explain: &mut dyn Explain) -> usize {
+        summarize(&input, 1, explain)
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            400
+        );
+    }
+}
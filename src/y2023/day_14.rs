@@ -0,0 +1,266 @@
+pub const DAY_STR: &str = "2023/day_14";
+
+pub use part_1::solution as part_1;
+pub use part_2::solution as part_2;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let lines = validate::lines(input).collect::<Vec<_>>();
+    validate::grid(&mut problems, &lines, "O#.");
+
+    problems.into_vec()
+}
+
+mod part_1 {
+
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+
+    use itertools::Itertools;
+    use tracing::{debug, trace};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Direction {
+        North,
+        West,
+        South,
+        East,
+    }
+
+    impl From<char> for Direction {
+        fn from(value: char) -> Self {
+            match value {
+                'N' => Self::North,
+                'W' => Self::West,
+                'S' => Self::South,
+                'E' => Self::East,
+                _x => panic!("Encounter unexpected direction `{_x}`"),
+            }
+        }
+    }
+
+    // a sequence of tilts like `NWSE`, repeated for as many steps as asked for
+    pub fn parse_program(program: &str) -> Vec<Direction> {
+        let program = program.chars().map(Direction::from).collect_vec();
+        assert!(
+            !program.is_empty(),
+            "A tilt program needs at least one tilt"
+        );
+        program
+    }
+
+    // the platform as one byte per tile, `O` for round rocks, `#` for cube
+    // rocks and `.` for empty space, row by row
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    pub struct Platform {
+        pub width: usize,
+        pub height: usize,
+        tiles: Vec<u8>,
+    }
+
+    impl Debug for Platform {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("Platform(")?;
+            for row in self.tiles.chunks(self.width) {
+                f.write_str("\n\t")?;
+                f.write_str(&String::from_utf8_lossy(row))?;
+            }
+            f.write_str("\n)")
+        }
+    }
+
+    impl Platform {
+        pub fn new(input: &str) -> Self {
+            let rows = input.lines().map(|line| line.as_bytes()).collect_vec();
+            let width = rows.first().map_or(0, |row| row.len());
+            assert!(
+                rows.iter().all(|row| row.len() == width),
+                "Every row of the platform must be as wide as the first"
+            );
+            if let Some(c) = rows
+                .iter()
+                .flat_map(|row| row.iter())
+                .find(|c| !b"O#.".contains(c))
+            {
+                panic!("Encounter unexpected tile `{}`", *c as char);
+            }
+
+            Platform {
+                width,
+                height: rows.len(),
+                tiles: rows.concat(),
+            }
+        }
+
+        // Rolls every round rock as far as it goes towards `direction`, one
+        // line at a time starting from the edge they roll to, by keeping the
+        // next free tile of the line.
+        pub fn tilt(&mut self, direction: Direction) {
+            let (width, height) = (self.width as isize, self.height as isize);
+            // the lines the rocks roll along, and the first tile and the stride
+            // of each, starting from the edge
+            let (lines, len) = match direction {
+                Direction::North | Direction::South => (self.width, self.height),
+                Direction::West | Direction::East => (self.height, self.width),
+            };
+            let line = |i: isize| match direction {
+                Direction::North => (i, width),
+                Direction::South => ((height - 1) * width + i, -width),
+                Direction::West => (i * width, 1),
+                Direction::East => (i * width + width - 1, -1),
+            };
+
+            for i in 0..lines as isize {
+                let (first, stride) = line(i);
+                let at = |k: usize| (first + k as isize * stride) as usize;
+                let mut free = 0;
+                for k in 0..len {
+                    match self.tiles[at(k)] {
+                        b'#' => free = k + 1,
+                        b'O' => {
+                            self.tiles[at(k)] = b'.';
+                            self.tiles[at(free)] = b'O';
+                            free += 1;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // Runs `steps` tilts of `program`, repeating it as needed. Once a
+        // platform comes up again at the same point in the program, the
+        // tilts in between repeat forever, so the rest are skipped.
+        pub fn run(&mut self, program: &[Direction], steps: u64) {
+            let mut seen = HashMap::new();
+            let mut step = 0;
+            while step < steps {
+                let at = (step % program.len() as u64) as usize;
+                if let Some(previous) = seen.insert((self.clone(), at), step) {
+                    let period = step - previous;
+                    debug!(previous, period, "loop found");
+                    step = steps - (steps - step) % period;
+                    seen.clear();
+                    if step == steps {
+                        break;
+                    }
+                }
+
+                self.tilt(program[at]);
+                trace!(step, platform = ?self, "tilted");
+                step += 1;
+            }
+        }
+
+        // the load on `side`, each round rock weighs 1 on the opposite edge and
+        // 1 more for each tile closer to `side`
+        pub fn load(&self, side: Direction) -> usize {
+            self.tiles
+                .iter()
+                .positions(|tile| *tile == b'O')
+                .map(|i| {
+                    let (x, y) = (i % self.width, i / self.width);
+                    match side {
+                        Direction::North => self.height - y,
+                        Direction::South => y + 1,
+                        Direction::West => self.width - x,
+                        Direction::East => x + 1,
+                    }
+                })
+                .sum()
+        }
+    }
+
+    pub fn solution(input: String) -> usize {
+        let mut platform = Platform::new(&input);
+        platform.tilt(Direction::North);
+        trace!(?platform, "tilted north");
+
+        platform.load(Direction::North)
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            136
+        );
+    }
+
+    #[test]
+    fn tilt_programs() {
+        let platform = Platform::new(
+            &std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                .unwrap(),
+        );
+
+        // the platform after one spin cycle in the puzzle
+        let mut spun = platform.clone();
+        spun.run(&parse_program("NWSE"), 4);
+        assert_eq!(
+            spun,
+            Platform::new(
+                ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n\
+                 .O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#...."
+            )
+        );
+
+        // skipping ahead through the loop lands where tilting one at a time does
+        for program in ["NWSE", "NNEW", "S", "WSSEN"] {
+            let program = parse_program(program);
+            for steps in [0, 1, 7, 1_003] {
+                let mut skipped = platform.clone();
+                skipped.run(&program, steps);
+                let mut tilted = platform.clone();
+                for step in 0..steps as usize {
+                    tilted.tilt(program[step % program.len()]);
+                }
+                assert_eq!(skipped, tilted);
+            }
+        }
+
+        // the load on each side once tilted towards it
+        for (side, load) in [
+            (Direction::North, 136),
+            (Direction::West, 147),
+            (Direction::South, 132),
+            (Direction::East, 105),
+        ] {
+            let mut tilted = platform.clone();
+            tilted.run(&[side], 1_000_000_000);
+            assert_eq!(tilted.load(side), load);
+        }
+    }
+}
+
+mod part_2 {
+
+    use super::part_1::{parse_program, Direction, Platform};
+
+    pub fn solution(input: String) -> usize {
+        let mut platform = Platform::new(&input);
+        platform.run(&parse_program("NWSE"), 4 * 1_000_000_000);
+
+        platform.load(Direction::North)
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            64
+        );
+    }
+}
@@ -0,0 +1,418 @@
+pub const DAY_STR: &str = "2023/day_15";
+
+pub use part_1::solution as part_1;
+pub use part_1::solution_explained as part_1_explained;
+pub use part_1::solution_streaming as part_1_streaming;
+pub use part_2::solution as part_2;
+pub use part_2::solution_explained as part_2_explained;
+pub use part_2::solution_streaming as part_2_streaming;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    // a newline would be hashed as part of the step it ends up in
+    if input.contains('\n') {
+        problems.input("the steps should be on a single line, without a trailing newline");
+    }
+
+    for (n, line) in validate::lines(input) {
+        for step in line.split(',') {
+            let label = if let Some(label) = step.strip_suffix('-') {
+                label
+            } else if let Some((label, focal_length)) = step.split_once('=') {
+                if !matches!(focal_length.parse::<u8>(), Ok(1..=9)) {
+                    problems.at(
+                        n,
+                        format!("focal length in `{}` should be a digit from 1 to 9", step),
+                    );
+                }
+                label
+            } else {
+                problems.at(
+                    n,
+                    format!("step `{}` should end in `-` or `=<focal length>`", step),
+                );
+                continue;
+            };
+            if label.is_empty() || !label.chars().all(|c| c.is_ascii_lowercase()) {
+                problems.at(
+                    n,
+                    format!("label in `{}` should be lowercase letters", step),
+                );
+            }
+        }
+    }
+
+    problems.into_vec()
+}
+
+mod part_1 {
+
+    use std::io::BufRead;
+
+    use rstest::*;
+
+    use crate::explain;
+    use crate::explain::{Explain, Silent};
+    use crate::streaming;
+
+    pub fn hash_alg(input: String) -> u8 {
+        // unknown if doing a wrapping_add is allowed?
+
+        input
+            .chars()
+            .fold(0, |h, c| (h.wrapping_add(c as u8)).wrapping_mul(17))
+    }
+
+    #[rstest]
+    #[case("", 0)]
+    #[case("H", 200)]
+    #[case("HA", 153)]
+    #[case("HAS", 172)]
+    #[case("HASH", 52)]
+    fn test_hash_alg(#[case] input: String, #[case] result: u8) {
+        assert_eq!(hash_alg(input), result);
+    }
+
+    pub fn solution(input: String) -> u32 {
+        solution_explained(input, &mut Silent)
+    }
+
+    pub fn solution_explained(input: String, explain: &mut dyn Explain) -> u32 {
+        sum_hashes(input.split_terminator(','), explain)
+    }
+
+    pub fn solution_streaming(input: impl BufRead) -> u32 {
+        sum_hashes(streaming::split(input, b','), &mut Silent)
+    }
+
+    fn sum_hashes(steps: impl Iterator<Item = impl AsRef<str>>, explain: &mut dyn Explain) -> u32 {
+        steps
+            .map(|s| {
+                let s = s.as_ref();
+                let hash = hash_alg(s.to_string());
+                explain!(explain, "{} hashes to {}", s, hash);
+                hash as u32
+            })
+            .sum::<u32>()
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_1/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            1320
+        );
+    }
+
+    #[test]
+    fn streaming_sample() {
+        let file =
+            std::fs::File::open(format!("inputs/{}/part_1/sample.txt", super::DAY_STR)).unwrap();
+        assert_eq!(solution_streaming(std::io::BufReader::new(file)), 1320);
+    }
+
+    #[test]
+    fn streaming_matches() {
+        // empty steps hash to 0 either way
+        for input in ["rn=1,,cm-", ",rn=1,cm-,", "rn=1,cm-,,"] {
+            assert_eq!(
+                solution_streaming(input.as_bytes()),
+                solution(input.to_string()),
+                "solving `{}`",
+                input
+            );
+        }
+    }
+}
+
+mod part_2 {
+
+    use std::collections::{BTreeSet, HashMap};
+    use std::fmt::Display;
+    use std::io::BufRead;
+
+    use itertools::Itertools;
+
+    use super::part_1::hash_alg;
+
+    use crate::explain;
+    use crate::explain::{Explain, Silent};
+    use crate::streaming;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Step {
+        // `label=focal_length`
+        Insert { label: String, focal_length: u8 },
+        // `label-`
+        Remove { label: String },
+    }
+
+    impl Step {
+        pub fn new(step: &str) -> Self {
+            if let Some((label, focal_length)) = step.split_once('=') {
+                Step::Insert {
+                    label: label.to_string(),
+                    focal_length: focal_length
+                        .parse()
+                        .unwrap_or_else(|_| panic!("Bad focal length in `{step}`")),
+                }
+            } else if let Some(label) = step.strip_suffix('-') {
+                Step::Remove {
+                    label: label.to_string(),
+                }
+            } else {
+                panic!("Step `{step}` should end in `-` or `=<focal length>`")
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct LensBox {
+        // lenses in the order they went in, a removed lens leaves a gap until
+        // there are as many gaps as lenses
+        slots: Vec<Option<(String, u8)>>,
+        lenses: usize,
+    }
+
+    impl LensBox {
+        fn lenses(&self) -> impl Iterator<Item = (&str, u8)> {
+            self.slots
+                .iter()
+                .flatten()
+                .map(|(label, focal_length)| (label.as_str(), *focal_length))
+        }
+
+        fn power(&self, box_idx: usize) -> usize {
+            self.lenses()
+                .enumerate()
+                .map(|(slot, (_, focal_length))| (box_idx + 1) * (slot + 1) * focal_length as usize)
+                .sum()
+        }
+    }
+
+    // The 256 boxes of the HASHMAP procedure. Each label's slot is kept in an
+    // index, so replacing or removing a lens doesn't search its box, and the
+    // focusing power of a box is only worked out again once it has changed.
+    #[derive(Debug, Clone)]
+    pub struct LensBoxes {
+        boxes: Vec<LensBox>,
+        slots: HashMap<String, usize>,
+        powers: Vec<usize>,
+        changed: BTreeSet<usize>,
+    }
+
+    impl Default for LensBoxes {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl LensBoxes {
+        pub fn new() -> Self {
+            LensBoxes {
+                boxes: vec![LensBox::default(); 256],
+                slots: HashMap::new(),
+                powers: vec![0; 256],
+                changed: BTreeSet::new(),
+            }
+        }
+
+        pub fn apply(&mut self, step: Step) {
+            match step {
+                Step::Insert {
+                    label,
+                    focal_length,
+                } => self.insert(label, focal_length),
+                Step::Remove { label } => self.remove(&label),
+            }
+        }
+
+        // replaces the lens with the same label, or puts it behind the others
+        pub fn insert(&mut self, label: String, focal_length: u8) {
+            let box_idx = hash_alg(label.clone()) as usize;
+            let lens_box = &mut self.boxes[box_idx];
+            if let Some(slot) = self.slots.get(&label) {
+                lens_box.slots[*slot] = Some((label, focal_length));
+            } else {
+                self.slots.insert(label.clone(), lens_box.slots.len());
+                lens_box.slots.push(Some((label, focal_length)));
+                lens_box.lenses += 1;
+            }
+            self.changed.insert(box_idx);
+        }
+
+        // takes out the lens with the label if there is one, the lenses
+        // behind it move forward
+        pub fn remove(&mut self, label: &str) {
+            let Some(slot) = self.slots.remove(label) else {
+                return;
+            };
+            let box_idx = hash_alg(label.to_string()) as usize;
+            let lens_box = &mut self.boxes[box_idx];
+            lens_box.slots[slot] = None;
+            lens_box.lenses -= 1;
+
+            // closing the gaps moves every lens, which is paid for by the
+            // removals that made them
+            if lens_box.slots.len() >= 2 * lens_box.lenses {
+                lens_box.slots.retain(Option::is_some);
+                for (slot, (label, _)) in lens_box.slots.iter().flatten().enumerate() {
+                    *self.slots.get_mut(label).unwrap() = slot;
+                }
+            }
+            self.changed.insert(box_idx);
+        }
+
+        // the lenses in a box like `[rn 1] [cm 2]`
+        pub fn format_box(&self, box_idx: u8) -> String {
+            self.lenses(box_idx)
+                .map(|(label, focal_length)| format!("[{} {}]", label, focal_length))
+                .join(" ")
+        }
+
+        // the lenses in a box, front to back
+        pub fn lenses(&self, box_idx: u8) -> impl Iterator<Item = (&str, u8)> {
+            self.boxes[box_idx as usize].lenses()
+        }
+
+        // the boxes with at least one lens
+        pub fn non_empty(&self) -> impl Iterator<Item = u8> + '_ {
+            (0..=255).filter(|box_idx| self.boxes[*box_idx as usize].lenses > 0)
+        }
+
+        pub fn box_power(&mut self, box_idx: u8) -> usize {
+            self.update_powers();
+            self.powers[box_idx as usize]
+        }
+
+        pub fn focusing_power(&mut self) -> usize {
+            self.update_powers();
+            self.powers.iter().sum()
+        }
+
+        fn update_powers(&mut self) {
+            for box_idx in std::mem::take(&mut self.changed) {
+                self.powers[box_idx] = self.boxes[box_idx].power(box_idx);
+            }
+        }
+    }
+
+    // the non-empty boxes the way the puzzle shows them, like `Box 0: [rn 1] [cm 2]`
+    impl Display for LensBoxes {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            for box_idx in self.non_empty() {
+                writeln!(f, "Box {}: {}", box_idx, self.format_box(box_idx))?;
+            }
+            Ok(())
+        }
+    }
+
+    pub fn solution(input: String) -> usize {
+        solution_explained(input, &mut Silent)
+    }
+
+    pub fn solution_explained(input: String, explain: &mut dyn Explain) -> usize {
+        focusing_power(input.split_terminator(','), explain)
+    }
+
+    pub fn solution_streaming(input: impl BufRead) -> usize {
+        focusing_power(streaming::split(input, b','), &mut Silent)
+    }
+
+    fn focusing_power(
+        steps: impl Iterator<Item = impl AsRef<str>>,
+        explain: &mut dyn Explain,
+    ) -> usize {
+        // steps are applied as they are read, only the boxes are kept around
+        let mut boxes = LensBoxes::new();
+        for step in steps {
+            boxes.apply(Step::new(step.as_ref()));
+        }
+
+        if explain.enabled() {
+            for box_idx in boxes.non_empty().collect_vec() {
+                let power = boxes.box_power(box_idx);
+                explain!(
+                    explain,
+                    "Box {}: {}, focusing power {}",
+                    box_idx,
+                    boxes.format_box(box_idx),
+                    power
+                );
+            }
+        }
+        boxes.focusing_power()
+    }
+
+    // the focusing power after each step, worked out as the steps are read
+    pub fn focusing_powers(
+        steps: impl Iterator<Item = impl AsRef<str>>,
+    ) -> impl Iterator<Item = usize> {
+        let mut boxes = LensBoxes::new();
+        steps.map(move |step| {
+            boxes.apply(Step::new(step.as_ref()));
+            boxes.focusing_power()
+        })
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            solution(
+                std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+                    .unwrap()
+            ),
+            145
+        );
+    }
+
+    #[test]
+    fn streaming_sample() {
+        let file =
+            std::fs::File::open(format!("inputs/{}/part_2/sample.txt", super::DAY_STR)).unwrap();
+        assert_eq!(solution_streaming(std::io::BufReader::new(file)), 145);
+    }
+
+    #[test]
+    fn lens_boxes() {
+        let input = std::fs::read_to_string(format!("inputs/{}/part_2/sample.txt", super::DAY_STR))
+            .unwrap();
+
+        // the boxes after each step in the puzzle
+        assert_eq!(
+            focusing_powers(input.split_terminator(',')).collect_vec(),
+            vec![1, 1, 7, 11, 5, 21, 93, 153, 81, 153, 145]
+        );
+
+        let mut boxes = LensBoxes::new();
+        for step in input.split_terminator(',') {
+            boxes.apply(Step::new(step));
+        }
+        assert_eq!(
+            boxes.to_string(),
+            "Box 0: [rn 1] [cm 2]\nBox 3: [ot 7] [ab 5] [pc 6]\n"
+        );
+
+        // the gaps left by removed lenses don't count as slots
+        for label in ["aa", "bb", "cc", "dd"] {
+            boxes.insert(label.to_string(), 1);
+        }
+        boxes.remove("ot");
+        boxes.remove("ab");
+        boxes.remove("rn");
+        boxes.insert("pc".to_string(), 4);
+        boxes.remove("missing");
+        assert_eq!(boxes.lenses(3).collect_vec(), vec![("pc", 4)]);
+        assert_eq!(boxes.lenses(0).collect_vec(), vec![("cm", 2)]);
+        assert_eq!(boxes.focusing_power(), 2 + 4 * 4 + 243 + 37 + 87 + 137);
+    }
+}
@@ -1,8 +1,22 @@
-pub const DAY_STR: &str = "day_16";
+pub const DAY_STR: &str = "2023/day_16";
 
 pub use part_1::solution as part_1;
 pub use part_2::solution as part_2;
 
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let lines = validate::lines(input).collect::<Vec<_>>();
+    validate::grid(&mut problems, &lines, ".|-/\\");
+
+    problems.into_vec()
+}
+
 mod part_1 {
 
     use itertools::Itertools;
@@ -1,8 +1,22 @@
-pub const DAY_STR: &str = "day_17";
+pub const DAY_STR: &str = "2023/day_17";
 
 pub use part_1::solution as part_1;
 pub use part_2::solution as part_2;
 
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let lines = validate::lines(input).collect::<Vec<_>>();
+    validate::grid(&mut problems, &lines, "123456789");
+
+    problems.into_vec()
+}
+
 mod part_1 {
     use std::{
         cmp::{min, Reverse},
@@ -10,6 +24,7 @@ mod part_1 {
     };
 
     use itertools::Itertools;
+    use tracing::{debug, trace};
 
     #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
     pub enum Moves {
@@ -30,9 +45,6 @@ mod part_1 {
             .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect_vec())
             .collect_vec();
 
-        // dbg!(&grid);
-        // todo!();
-
         let mut visited: HashMap<Cell, u32> = HashMap::new();
 
         let mut fringe: BinaryHeap<Reverse<(u32, Cell)>> = BinaryHeap::new();
@@ -61,9 +73,7 @@ mod part_1 {
 
             visited.insert(state, cost);
 
-            // dbg!(cost);
-            // dbg!(fringe.len());
-            // dbg!(state);
+            trace!(cost, fringe = fringe.len(), ?state, "expanding state");
 
             match state.next_move {
                 Moves::UpDown => {
@@ -81,8 +91,7 @@ mod part_1 {
                                 x_i: new_xi,
                                 next_move: new_next_move,
                             };
-                            // dbg!(new_cell);
-                            // dbg!(new_cell_cost);
+                            trace!(?new_cell, new_cell_cost, "reached cell");
 
                             if let Some(old_cost) = visited.get(&new_cell) {
                                 assert!(*old_cost <= new_cell_cost);
@@ -118,8 +127,7 @@ mod part_1 {
                                 x_i: new_xi,
                                 next_move: new_next_move,
                             };
-                            // dbg!(new_cell);
-                            // dbg!(new_cell_cost);
+                            trace!(?new_cell, new_cell_cost, "reached cell");
 
                             if let Some(old_cost) = visited.get(&new_cell) {
                                 assert!(*old_cost <= new_cell_cost);
@@ -158,8 +166,7 @@ mod part_1 {
                                 x_i: new_xi,
                                 next_move: new_next_move,
                             };
-                            // dbg!(new_cell);
-                            // dbg!(new_cell_cost);
+                            trace!(?new_cell, new_cell_cost, "reached cell");
 
                             if let Some(old_cost) = visited.get(&new_cell) {
                                 assert!(*old_cost <= new_cell_cost);
@@ -195,8 +202,7 @@ mod part_1 {
                                 x_i: new_xi,
                                 next_move: new_next_move,
                             };
-                            // dbg!(new_cell);
-                            // dbg!(new_cell_cost);
+                            trace!(?new_cell, new_cell_cost, "reached cell");
 
                             if let Some(old_cost) = visited.get(&new_cell) {
                                 assert!(*old_cost <= new_cell_cost);
@@ -221,35 +227,26 @@ mod part_1 {
                     }
                 }
             }
-            // todo!();
-
-            // if visited.len() > 10 {
-            //     break;
-            // }
         }
 
-        // dbg!(&fringe);
-        // dbg!(&visited);
+        debug!(visited = visited.len(), "search finished");
 
         let ending_lr = visited.get(&Cell {
             y_i: grid.len() - 1,
             x_i: grid.len() - 1,
             next_move: Moves::LeftRight,
         });
-        // dbg!(ending_lr);
 
         let ending_ud = visited.get(&Cell {
             y_i: grid.len() - 1,
             x_i: grid.len() - 1,
             next_move: Moves::UpDown,
         });
-        // dbg!(ending_ud);
 
         let ending = min(ending_lr, ending_ud);
-        // dbg!(ending);
+        debug!(?ending_lr, ?ending_ud, "costs to reach the end");
 
         *ending.unwrap()
-        // todo!()
     }
 
     #[test]
@@ -272,6 +269,7 @@ mod part_2 {
     };
 
     use itertools::Itertools;
+    use tracing::{debug, trace};
 
     use super::part_1::*;
 
@@ -281,9 +279,6 @@ mod part_2 {
             .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect_vec())
             .collect_vec();
 
-        // dbg!(&grid);
-        // todo!();
-
         let mut visited: HashMap<Cell, u32> = HashMap::new();
 
         let mut fringe: BinaryHeap<Reverse<(u32, Cell)>> = BinaryHeap::new();
@@ -312,9 +307,7 @@ mod part_2 {
 
             visited.insert(state, cost);
 
-            // dbg!(cost);
-            // dbg!(fringe.len());
-            // dbg!(state);
+            trace!(cost, fringe = fringe.len(), ?state, "expanding state");
 
             match state.next_move {
                 Moves::UpDown => {
@@ -339,8 +332,7 @@ mod part_2 {
                                 x_i: new_xi,
                                 next_move: new_next_move,
                             };
-                            // dbg!(new_cell);
-                            // dbg!(new_cell_cost);
+                            trace!(?new_cell, new_cell_cost, "reached cell");
 
                             if let Some(old_cost) = visited.get(&new_cell) {
                                 assert!(*old_cost <= new_cell_cost);
@@ -383,8 +375,7 @@ mod part_2 {
                                 x_i: new_xi,
                                 next_move: new_next_move,
                             };
-                            // dbg!(new_cell);
-                            // dbg!(new_cell_cost);
+                            trace!(?new_cell, new_cell_cost, "reached cell");
 
                             if let Some(old_cost) = visited.get(&new_cell) {
                                 assert!(*old_cost <= new_cell_cost);
@@ -430,8 +421,7 @@ mod part_2 {
                                 x_i: new_xi,
                                 next_move: new_next_move,
                             };
-                            // dbg!(new_cell);
-                            // dbg!(new_cell_cost);
+                            trace!(?new_cell, new_cell_cost, "reached cell");
 
                             if let Some(old_cost) = visited.get(&new_cell) {
                                 assert!(*old_cost <= new_cell_cost);
@@ -474,8 +464,7 @@ mod part_2 {
                                 x_i: new_xi,
                                 next_move: new_next_move,
                             };
-                            // dbg!(new_cell);
-                            // dbg!(new_cell_cost);
+                            trace!(?new_cell, new_cell_cost, "reached cell");
 
                             if let Some(old_cost) = visited.get(&new_cell) {
                                 assert!(*old_cost <= new_cell_cost);
@@ -500,35 +489,26 @@ mod part_2 {
                     }
                 }
             }
-            // todo!();
-
-            // if visited.len() > 10 {
-            //     break;
-            // }
         }
 
-        // dbg!(&fringe);
-        // dbg!(&visited);
+        debug!(visited = visited.len(), "search finished");
 
         let ending_lr = visited.get(&Cell {
             y_i: grid.len() - 1,
             x_i: grid.len() - 1,
             next_move: Moves::LeftRight,
         });
-        // dbg!(ending_lr);
 
         let ending_ud = visited.get(&Cell {
             y_i: grid.len() - 1,
             x_i: grid.len() - 1,
             next_move: Moves::UpDown,
         });
-        // dbg!(ending_ud);
 
         let ending = min(ending_lr, ending_ud);
-        // dbg!(ending);
+        debug!(?ending_lr, ?ending_ud, "costs to reach the end");
 
         *ending.unwrap()
-        // todo!()
     }
 
     #[test]
@@ -1,10 +1,52 @@
-pub const DAY_STR: &str = "day_18";
+pub const DAY_STR: &str = "2023/day_18";
 
 pub use part_1::solution as part_1;
 pub use part_2::solution as part_2;
 
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    for (n, line) in validate::lines(input) {
+        let [dir, length, colour] = line.split(' ').collect::<Vec<_>>()[..] else {
+            problems.at(n, "expected a direction, a length and a colour");
+            continue;
+        };
+        if !["U", "D", "L", "R"].contains(&dir) {
+            problems.at(
+                n,
+                format!("unexpected direction `{}`, expected one of `UDLR`", dir),
+            );
+        }
+        problems.number::<u32>(n, length);
+        match colour
+            .strip_prefix("(#")
+            .and_then(|hex| hex.strip_suffix(')'))
+        {
+            Some(hex) if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+                // part 2 reads the last digit as the direction
+                if !"0123".contains(&hex[5..]) {
+                    problems.at(
+                        n,
+                        format!("colour `{}` should end in 0 to 3 to be a direction", colour),
+                    );
+                }
+            }
+            _ => problems.at(n, format!("`{}` is not a colour like `(#70c710)`", colour)),
+        }
+    }
+
+    problems.into_vec()
+}
+
 mod part_1 {
 
+    use std::iter;
+
     use itertools::Itertools;
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,13 +85,20 @@ mod part_1 {
             })
             .collect_vec();
 
+        lagoon_size(trenches)
+    }
+
+    // digs out the trench on a grid and flood fills the outside
+    pub fn lagoon_size(trenches: Vec<(Dir, u32)>) -> usize {
+        use Dir::*;
+
         let range_ud = trenches
             .iter()
             .filter_map(|(dir, mag)| {
                 if dir == &Down {
-                    Some(*mag as i32 + 1)
+                    Some(*mag as i32)
                 } else if dir == &Up {
-                    Some(-(*mag as i32 + 1))
+                    Some(-(*mag as i32))
                 } else {
                     None
                 }
@@ -58,15 +107,16 @@ mod part_1 {
                 *state += mag;
                 Some(*state)
             })
+            .chain(iter::once(0))
             .collect_vec();
 
         let range_lr = trenches
             .iter()
             .filter_map(|(dir, mag)| {
                 if dir == &Right {
-                    Some(*mag as i32 + 1)
+                    Some(*mag as i32)
                 } else if dir == &Left {
-                    Some(-(*mag as i32 + 1))
+                    Some(-(*mag as i32))
                 } else {
                     None
                 }
@@ -75,19 +125,22 @@ mod part_1 {
                 *state += mag;
                 Some(*state)
             })
+            .chain(iter::once(0))
             .collect_vec();
 
         // dbg!(&range_ud);
         // dbg!(&range_lr);
 
-        let n_rows = 1 + (range_ud.iter().max().unwrap() - range_ud.iter().min().unwrap()) as usize;
-        let n_cols = 1 + (range_lr.iter().max().unwrap() - range_lr.iter().min().unwrap()) as usize;
+        // leave a border of one empty tile around the trench
+        // so the flood fill from the corner reaches all of the outside
+        let n_rows = 3 + (range_ud.iter().max().unwrap() - range_ud.iter().min().unwrap()) as usize;
+        let n_cols = 3 + (range_lr.iter().max().unwrap() - range_lr.iter().min().unwrap()) as usize;
 
         // dbg!(n_rows);
         // dbg!(n_cols);
 
-        let mut y = -range_ud.iter().min().unwrap() as usize;
-        let mut x = -range_lr.iter().min().unwrap() as usize;
+        let mut y = (1 - range_ud.iter().min().unwrap()) as usize;
+        let mut x = (1 - range_lr.iter().min().unwrap()) as usize;
 
         // dbg!(x);
         // dbg!(y);
@@ -227,6 +280,12 @@ mod part_2 {
         // dbg!(&trenches);
         // todo!();
 
+        lagoon_size(trenches)
+    }
+
+    pub fn lagoon_size(trenches: Vec<(Dir, usize)>) -> usize {
+        use Dir::*;
+
         let range_ud = trenches
             .iter()
             .filter_map(|(dir, mag)| {
@@ -352,4 +411,61 @@ mod part_2 {
             952408144115
         );
     }
+
+    #[cfg(test)]
+    mod differential {
+        use itertools::Itertools;
+        use proptest::prelude::*;
+
+        use super::super::part_1;
+        use super::*;
+
+        // traces the outline of a histogram with columns of the given
+        // `(width, height)`, which is always a simple closed loop
+        fn histogram(columns: &[(usize, usize)]) -> Vec<(Dir, usize)> {
+            use Dir::*;
+
+            let mut trenches = vec![];
+            let mut height = 0;
+
+            for (width, new_height) in columns.iter().copied() {
+                match new_height.cmp(&height) {
+                    std::cmp::Ordering::Greater => trenches.push((Up, new_height - height)),
+                    std::cmp::Ordering::Less => trenches.push((Down, height - new_height)),
+                    std::cmp::Ordering::Equal => {}
+                }
+                trenches.push((Right, width));
+                height = new_height;
+            }
+            trenches.push((Down, height));
+            trenches.push((Left, columns.iter().map(|(width, _)| width).sum()));
+
+            // merge consecutive moves in the same direction
+            trenches
+                .into_iter()
+                .coalesce(|(d1, m1), (d2, m2)| {
+                    if d1 == d2 {
+                        Ok((d1, m1 + m2))
+                    } else {
+                        Err(((d1, m1), (d2, m2)))
+                    }
+                })
+                .collect_vec()
+        }
+
+        proptest! {
+            #[test]
+            fn matches_flood_fill(
+                columns in prop::collection::vec((1_usize..6, 1_usize..6), 1..6),
+            ) {
+                let trenches = histogram(&columns);
+                let small = trenches
+                    .iter()
+                    .map(|(dir, mag)| (*dir, *mag as u32))
+                    .collect_vec();
+
+                prop_assert_eq!(lagoon_size(trenches), part_1::lagoon_size(small));
+            }
+        }
+    }
 }
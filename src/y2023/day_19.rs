@@ -1,21 +1,131 @@
-pub const DAY_STR: &str = "day_19";
+pub const DAY_STR: &str = "2023/day_19";
 
 pub use part_1::solution as part_1;
 pub use part_2::solution as part_2;
 
+use itertools::Itertools;
+use std::collections::HashMap;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let blocks = validate::blocks(input);
+    let [workflows, parts] = &blocks[..] else {
+        problems.input("expected the workflows, a blank line and then the parts");
+        return problems.into_vec();
+    };
+
+    let mut defined = HashMap::new();
+    let mut targets = vec![];
+    for (n, line) in workflows {
+        let Some((name, rules)) = line.strip_suffix('}').and_then(|line| line.split_once('{'))
+        else {
+            problems.at(
+                *n,
+                format!(
+                    "expected a workflow like `px{{a<2006:qkq,rfg}}`, found `{}`",
+                    line
+                ),
+            );
+            continue;
+        };
+        if let Some(first) = defined.insert(name, *n) {
+            problems.at(
+                *n,
+                format!("workflow `{}` is already defined on line {}", name, first),
+            );
+        }
+
+        let rules = rules.split(',').collect_vec();
+        let (fallback, conditional) = rules.split_last().unwrap();
+        for rule in conditional {
+            let Some((condition, target)) = rule.split_once(':') else {
+                problems.at(
+                    *n,
+                    format!("rule `{}` should be a condition and a target", rule),
+                );
+                continue;
+            };
+            let mut chars = condition.chars();
+            if !(matches!(chars.next(), Some('x' | 'm' | 'a' | 's'))
+                && matches!(chars.next(), Some('<' | '>'))
+                && chars.as_str().parse::<u32>().is_ok())
+            {
+                problems.at(
+                    *n,
+                    format!("condition `{}` should look like `a<2006`", condition),
+                );
+            }
+            targets.push((*n, name, target));
+        }
+        targets.push((*n, name, fallback));
+    }
+
+    for (n, name, target) in targets {
+        if target != "A" && target != "R" && !defined.contains_key(target) {
+            problems.at(
+                n,
+                format!(
+                    "workflow `{}` sends parts to `{}` which doesn't exist",
+                    name, target
+                ),
+            );
+        }
+    }
+    if !defined.contains_key("in") {
+        problems.input("there is no `in` workflow to start from");
+    }
+
+    for (n, line) in parts {
+        let valid = line
+            .strip_prefix('{')
+            .and_then(|line| line.strip_suffix('}'))
+            .is_some_and(|ratings| {
+                let ratings = ratings.split(',').collect_vec();
+                ratings.len() == 4
+                    && ratings
+                        .iter()
+                        .zip(["x=", "m=", "a=", "s="])
+                        .all(|(rating, prefix)| {
+                            rating
+                                .strip_prefix(prefix)
+                                .is_some_and(|value| value.parse::<u32>().is_ok())
+                        })
+            });
+        if !valid {
+            problems.at(
+                *n,
+                format!(
+                    "expected a part like `{{x=787,m=2655,a=1222,s=2876}}`, found `{}`",
+                    line
+                ),
+            );
+        }
+    }
+
+    problems.into_vec()
+}
+
 mod part_1 {
     use std::collections::HashMap;
 
     use itertools::Itertools;
     use nom::{
         bytes::complete::tag,
-        character::complete::{self, alpha1, anychar, newline},
+        character::complete::{alpha1, anychar},
         combinator::opt,
-        error::Error,
-        multi::{many1, separated_list1},
-        sequence::{delimited, preceded, terminated, tuple},
+        multi::separated_list1,
+        sequence::{delimited, preceded, separated_pair, terminated, tuple},
         IResult,
     };
+    use tracing::{debug, trace};
+
+    use crate::parsing::{self, blank_lines, lines, unsigned};
 
     struct Rule {
         condition: Box<dyn Fn(Part) -> bool>,
@@ -30,7 +140,13 @@ mod part_1 {
         pub s: u32,
     }
 
-    fn parse_workflow(input: &str) -> IResult<&str, (String, Vec<Rule>)> {
+    // a rule as it's written, each part turns these into its own kind of rule
+    pub struct RawRule<'a> {
+        pub condition: Option<(char, char, u32)>,
+        pub destination: &'a str,
+    }
+
+    fn parse_workflow(input: &str) -> IResult<&str, (&str, Vec<RawRule<'_>>)> {
         let (input, name) = alpha1(input)?;
 
         let (input, rules) = delimited(
@@ -38,50 +154,18 @@ mod part_1 {
             separated_list1(
                 tag(","),
                 tuple((
-                    opt(terminated(
-                        tuple((anychar, anychar, complete::u32)),
-                        tag(":"),
-                    )),
+                    opt(terminated(tuple((anychar, anychar, unsigned)), tag(":"))),
                     alpha1,
                 )),
             ),
             tag("}"),
         )(input)?;
 
-        let name = name.to_string();
-
         let rules = rules
             .into_iter()
-            .map(|(cond, dest)| {
-                let condition: Box<dyn Fn(Part) -> bool> =
-                    if let Some((field, comparison, value)) = cond {
-                        match comparison {
-                            '>' => match field {
-                                'x' => Box::new(move |part: Part| part.x > value),
-                                'm' => Box::new(move |part: Part| part.m > value),
-                                'a' => Box::new(move |part: Part| part.a > value),
-                                's' => Box::new(move |part: Part| part.s > value),
-                                _ => panic!(),
-                            },
-                            '<' => match field {
-                                'x' => Box::new(move |part: Part| part.x < value),
-                                'm' => Box::new(move |part: Part| part.m < value),
-                                'a' => Box::new(move |part: Part| part.a < value),
-                                's' => Box::new(move |part: Part| part.s < value),
-                                _ => panic!(),
-                            },
-                            _ => panic!(),
-                        }
-                    } else {
-                        Box::new(|_part: Part| true)
-                    };
-
-                let destination = dest.to_string();
-
-                Rule {
-                    condition,
-                    destination,
-                }
+            .map(|(condition, destination)| RawRule {
+                condition,
+                destination,
             })
             .collect_vec();
 
@@ -90,32 +174,71 @@ mod part_1 {
 
     fn parse_part(input: &str) -> IResult<&str, Part> {
         let (input, _) = tag("{")(input)?;
-        let (input, x) = preceded(tag("x="), complete::u32)(input)?;
+        let (input, x) = preceded(tag("x="), unsigned)(input)?;
         let (input, _) = tag(",")(input)?;
-        let (input, m) = preceded(tag("m="), complete::u32)(input)?;
+        let (input, m) = preceded(tag("m="), unsigned)(input)?;
         let (input, _) = tag(",")(input)?;
-        let (input, a) = preceded(tag("a="), complete::u32)(input)?;
+        let (input, a) = preceded(tag("a="), unsigned)(input)?;
         let (input, _) = tag(",")(input)?;
-        let (input, s) = preceded(tag("s="), complete::u32)(input)?;
+        let (input, s) = preceded(tag("s="), unsigned)(input)?;
         let (input, _) = tag("}")(input)?;
 
         Ok((input, Part { x, m, a, s }))
     }
 
+    pub type Workflows<'a> = Vec<(&'a str, Vec<RawRule<'a>>)>;
+
+    pub fn parse_system(input: &str) -> IResult<&str, (Workflows<'_>, Vec<Part>)> {
+        separated_pair(lines(parse_workflow), blank_lines, lines(parse_part))(input)
+    }
+
+    fn to_rule(rule: RawRule) -> Rule {
+        let condition: Box<dyn Fn(Part) -> bool> =
+            if let Some((field, comparison, value)) = rule.condition {
+                match comparison {
+                    '>' => match field {
+                        'x' => Box::new(move |part: Part| part.x > value),
+                        'm' => Box::new(move |part: Part| part.m > value),
+                        'a' => Box::new(move |part: Part| part.a > value),
+                        's' => Box::new(move |part: Part| part.s > value),
+                        _ => panic!(),
+                    },
+                    '<' => match field {
+                        'x' => Box::new(move |part: Part| part.x < value),
+                        'm' => Box::new(move |part: Part| part.m < value),
+                        'a' => Box::new(move |part: Part| part.a < value),
+                        's' => Box::new(move |part: Part| part.s < value),
+                        _ => panic!(),
+                    },
+                    _ => panic!(),
+                }
+            } else {
+                Box::new(|_part: Part| true)
+            };
+
+        Rule {
+            condition,
+            destination: rule.destination.to_string(),
+        }
+    }
+
     pub fn solution(input: String) -> u32 {
-        let (input, workflows) = separated_list1(newline, parse_workflow)(&input).unwrap();
-        let (input, _) = many1(newline::<&str, Error<&str>>)(input).unwrap();
-        let (_input, parts) = separated_list1(newline, parse_part)(input).unwrap();
+        let (workflows, parts) = parsing::parse(parse_system, &input);
 
         let mut workflow_map = HashMap::new();
 
         workflows.into_iter().for_each(|(name, rules)| {
-            workflow_map.insert(name, rules);
+            workflow_map.insert(
+                name.to_string(),
+                rules.into_iter().map(to_rule).collect_vec(),
+            );
         });
 
-        // dbg!(&workflow_map.keys());
-
-        // dbg!(&parts);
+        debug!(
+            workflows = workflow_map.len(),
+            parts = parts.len(),
+            "parsed system"
+        );
 
         let accepted = parts
             .into_iter()
@@ -123,7 +246,7 @@ mod part_1 {
                 let mut workflow = "in".to_string();
 
                 while &workflow != "A" && &workflow != "R" {
-                    // dbg!(&workflow);
+                    trace!(?part, %workflow, "sorting part");
                     let rules = workflow_map.get(&workflow).unwrap();
                     workflow = rules
                         .iter()
@@ -142,7 +265,7 @@ mod part_1 {
             })
             .collect_vec();
 
-        // dbg!(&accepted);
+        debug!(accepted = accepted.len(), "sorted parts");
 
         accepted.iter().map(|p| p.x + p.m + p.a + p.s).sum()
     }
@@ -161,20 +284,14 @@ mod part_1 {
 
 mod part_2 {
 
-    use super::part_1::Part;
+    use super::part_1::{parse_system, Part, RawRule};
 
     use std::collections::HashMap;
 
     use itertools::Itertools;
-    use nom::{
-        bytes::complete::tag,
-        character::complete::{self, alpha1, anychar, newline},
-        combinator::opt,
-        error::Error,
-        multi::{many1, separated_list1},
-        sequence::{delimited, preceded, terminated, tuple},
-        IResult,
-    };
+    use tracing::{debug, trace};
+
+    use crate::parsing;
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     enum Comparison {
@@ -448,87 +565,42 @@ mod part_2 {
         }
     }
 
-    fn parse_workflow(input: &str) -> IResult<&str, (String, Vec<Rule>)> {
-        let (input, name) = alpha1(input)?;
-
-        let (input, rules) = delimited(
-            tag("{"),
-            separated_list1(
-                tag(","),
-                tuple((
-                    opt(terminated(
-                        tuple((anychar, anychar, complete::u32)),
-                        tag(":"),
-                    )),
-                    alpha1,
-                )),
-            ),
-            tag("}"),
-        )(input)?;
-
-        let name = name.to_string();
-
-        let rules = rules
-            .into_iter()
-            .map(|(cond, dest)| {
-                let condition = if let Some((field, comp, value)) = cond {
-                    Some(Condition {
-                        field: match field {
-                            'x' => Field::X,
-                            'm' => Field::M,
-                            'a' => Field::A,
-                            's' => Field::S,
-                            _ => panic!(),
-                        },
-                        comp: match comp {
-                            '>' => Comparison::Greater,
-                            '<' => Comparison::Lesser,
-                            _ => panic!(),
-                        },
-                        value,
-                    })
-                } else {
-                    None
-                };
-
-                let destination = dest.to_string();
-
-                Rule {
-                    condition,
-                    destination,
-                }
-            })
-            .collect_vec();
-
-        Ok((input, (name, rules)))
-    }
-
-    fn parse_part(input: &str) -> IResult<&str, Part> {
-        let (input, _) = tag("{")(input)?;
-        let (input, x) = preceded(tag("x="), complete::u32)(input)?;
-        let (input, _) = tag(",")(input)?;
-        let (input, m) = preceded(tag("m="), complete::u32)(input)?;
-        let (input, _) = tag(",")(input)?;
-        let (input, a) = preceded(tag("a="), complete::u32)(input)?;
-        let (input, _) = tag(",")(input)?;
-        let (input, s) = preceded(tag("s="), complete::u32)(input)?;
-        let (input, _) = tag("}")(input)?;
+    fn to_rule(rule: RawRule) -> Rule {
+        let condition = rule.condition.map(|(field, comp, value)| Condition {
+            field: match field {
+                'x' => Field::X,
+                'm' => Field::M,
+                'a' => Field::A,
+                's' => Field::S,
+                _ => panic!(),
+            },
+            comp: match comp {
+                '>' => Comparison::Greater,
+                '<' => Comparison::Lesser,
+                _ => panic!(),
+            },
+            value,
+        });
 
-        Ok((input, Part { x, m, a, s }))
+        Rule {
+            condition,
+            destination: rule.destination.to_string(),
+        }
     }
 
     pub fn solution(input: String) -> u64 {
-        let (input, workflows) = separated_list1(newline, parse_workflow)(&input).unwrap();
-        let (input, _) = many1(newline::<&str, Error<&str>>)(input).unwrap();
-        let (_input, _parts) = separated_list1(newline, parse_part)(input).unwrap();
+        let (workflows, _parts) = parsing::parse(parse_system, &input);
 
         let mut workflow_map = HashMap::new();
 
         workflows.into_iter().for_each(|(name, rules)| {
-            workflow_map.insert(name, rules);
+            workflow_map.insert(
+                name.to_string(),
+                rules.into_iter().map(to_rule).collect_vec(),
+            );
         });
 
-        // dbg!(&workflow_map);
+        debug!(workflows = workflow_map.len(), "parsed workflows");
 
         let workflow = "in".to_string();
 
@@ -553,7 +625,7 @@ mod part_2 {
         let mut accepted_prs = vec![];
 
         while let Some((part_range, name)) = part_ranges.pop() {
-            // dbg!(&name);
+            trace!(?part_range, %name, "sorting part range");
 
             if &name == "A" {
                 accepted_prs.push(part_range);
@@ -563,7 +635,6 @@ mod part_2 {
             }
 
             let rules = workflow_map.get(&name).unwrap();
-            // dbg!(rules);
 
             let mut remaining_prs = vec![part_range];
 
@@ -584,18 +655,12 @@ mod part_2 {
                         }
                     })
                     .collect_vec();
-                // dbg!(&remaining_prs);
             }
-
-            // dbg!(&part_ranges);
-
-            // todo!();
         }
 
-        accepted_prs.iter().map(|pr| pr.get_area()).sum()
+        debug!(accepted = accepted_prs.len(), "sorted part ranges");
 
-        // dbg!(part_ranges);
-        // dbg!(accepted_prs);
+        accepted_prs.iter().map(|pr| pr.get_area()).sum()
 
         // todo!()
     }
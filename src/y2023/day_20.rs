@@ -1,8 +1,67 @@
-pub const DAY_STR: &str = "day_20";
+pub const DAY_STR: &str = "2023/day_20";
 
 pub use part_1::solution as part_1;
 pub use part_2::solution as part_2;
 
+use std::collections::HashMap;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let mut defined = HashMap::new();
+    let mut broadcasters = vec![];
+    for (n, line) in validate::lines(input) {
+        let Some((module, _outputs)) = line.split_once(" -> ") else {
+            problems.at(
+                n,
+                format!("expected a module like `%a -> b, c`, found `{}`", line),
+            );
+            continue;
+        };
+        let name = if module == "broadcaster" {
+            broadcasters.push(n);
+            module
+        } else if let Some(name) = module.strip_prefix(['%', '&']) {
+            name
+        } else {
+            problems.at(
+                n,
+                format!(
+                    "module `{}` should be `broadcaster` or start with `%` or `&`",
+                    module
+                ),
+            );
+            continue;
+        };
+        if let Some(first) = defined.insert(name, n) {
+            problems.at(
+                n,
+                format!("module `{}` is already defined on line {}", name, first),
+            );
+        }
+    }
+
+    match broadcasters.len() {
+        0 => problems.input("expected one `broadcaster` module, found none"),
+        1 => {}
+        count => {
+            for n in broadcasters {
+                problems.at(
+                    n,
+                    format!("expected one `broadcaster` module, found {}", count),
+                );
+            }
+        }
+    }
+
+    problems.into_vec()
+}
+
 mod part_1 {
 
     use std::{
@@ -12,21 +71,21 @@ mod part_1 {
 
     use nom::{
         bytes::complete::{tag, take_until1},
-        character::complete::{alpha1, newline},
+        character::complete::alpha1,
         multi::separated_list1,
         sequence::separated_pair,
         IResult,
     };
+    use tracing::{debug, trace};
+
+    use crate::parsing::{self, lines};
 
     pub fn parse_modules(input: &str) -> IResult<&str, Vec<(&str, Vec<&str>)>> {
-        separated_list1(
-            newline,
-            separated_pair(
-                take_until1(" "),
-                tag(" -> "),
-                separated_list1(tag(", "), alpha1),
-            ),
-        )(input)
+        lines(separated_pair(
+            take_until1(" "),
+            tag(" -> "),
+            separated_list1(tag(", "), alpha1),
+        ))(input)
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -156,7 +215,7 @@ mod part_1 {
     }
 
     pub fn solution(input: String) -> usize {
-        let (_input, parsed) = parse_modules(&input).unwrap();
+        let parsed = parsing::parse(parse_modules, &input);
 
         // only a single broadcaster module
         let mut broadcaster = vec![];
@@ -203,9 +262,7 @@ mod part_1 {
                 (name, module)
             }));
 
-        // dbg!(&computer);
-
-        // dbg!(&broadcaster);
+        debug!(modules = computer.len(), ?broadcaster, "built computer");
 
         let mut num_low_pulses = 0;
         let mut num_high_pulses = 0;
@@ -222,12 +279,10 @@ mod part_1 {
 
             num_low_pulses += 1; // for button -Low-> broadcaster
 
-            // dbg!(&signals);
-
             let mut processed_signals = vec![];
 
             while let Some(signal) = signals.pop_front() {
-                // dbg!(&signals);
+                trace!(?signal, "pulse sent");
                 processed_signals.push(signal.clone());
 
                 match signal.pulse {
@@ -253,12 +308,10 @@ mod part_1 {
                 }
             }
 
-            // dbg!(&computer);
-            // dbg!(processed_signals);
+            debug!(pulses = processed_signals.len(), "button pressed");
         }
 
-        // dbg!(num_high_pulses);
-        // dbg!(num_low_pulses);
+        debug!(num_high_pulses, num_low_pulses, "counted pulses");
 
         num_high_pulses * num_low_pulses
     }
@@ -291,11 +344,14 @@ mod part_2 {
     use std::collections::{HashMap, VecDeque};
 
     use itertools::Itertools;
+    use tracing::{debug, trace};
 
     use super::part_1::*;
 
+    use crate::parsing;
+
     pub fn solution(input: String) -> usize {
-        let (_input, parsed) = parse_modules(&input).unwrap();
+        let parsed = parsing::parse(parse_modules, &input);
 
         // only a single broadcaster module
         let mut broadcaster = vec![];
@@ -342,16 +398,14 @@ mod part_2 {
                 (name, module)
             }));
 
-        // dbg!(&computer);
-
-        // dbg!(&broadcaster);
+        debug!(modules = computer.len(), ?broadcaster, "built computer");
 
         let (_name, special_mod) = &computer
             .iter()
             .find(|(_name, module)| module.base.outputs.contains(&"rx"))
             .unwrap();
 
-        // dbg!(&special_mod);
+        debug!(?special_mod, "found module feeding `rx`");
 
         let key_modules = match &special_mod.kind {
             Kind::FlipFlop { on: _ } => panic!(),
@@ -361,10 +415,6 @@ mod part_2 {
         let mut key_modules: HashMap<&str, usize> =
             HashMap::from_iter(key_modules.iter().map(|&name| (name, 0)));
 
-        // dbg!(&key_modules);
-
-        // todo!();
-
         let mut button_presses: usize = 0;
         'outer: loop {
             let mut signals = broadcaster
@@ -381,11 +431,15 @@ mod part_2 {
             let mut processed_signals = vec![];
 
             while let Some(signal) = signals.pop_front() {
-                // dbg!(&signals);
+                trace!(?signal, "pulse sent");
                 processed_signals.push(signal.clone());
 
                 if let Some(key_mod) = key_modules.get_mut(signal.destination) {
                     if signal.pulse == Pulse::Low {
+                        debug!(
+                            module = signal.destination,
+                            button_presses, "key module hit"
+                        );
                         *key_mod = button_presses;
                         if key_modules.values().all(|x| *x != 0) {
                             break 'outer;
@@ -411,15 +465,12 @@ mod part_2 {
                 }
             }
 
-            // dbg!(processed_signals);
             if button_presses > 10000 {
                 break 'outer;
             }
-
-            // dbg!(&computer);
         }
 
-        // dbg!(&key_modules);
+        debug!(?key_modules, "cycle lengths");
 
         // this assumes the following
         // - that each key_module creates a LOW(or HIGH?) pulse on at cycle length
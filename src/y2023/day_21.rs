@@ -1,8 +1,32 @@
-pub const DAY_STR: &str = "day_21";
+pub const DAY_STR: &str = "2023/day_21";
 
 pub use part_1::solution as part_1;
 pub use part_2::solution as part_2;
 
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let lines = validate::lines(input).collect::<Vec<_>>();
+    validate::grid(&mut problems, &lines, ".#S");
+    validate::exactly_one(&mut problems, input, 'S');
+
+    // part 2 relies on the garden repeating around a centred start
+    let size = lines.len();
+    let centre = lines
+        .get(size / 2)
+        .and_then(|(_, line)| line.chars().nth(size / 2));
+    if centre != Some('S') || lines.iter().any(|(_, line)| line.chars().count() != size) {
+        problems.input("part 2 expects a square grid with `S` in the centre");
+    }
+
+    problems.into_vec()
+}
+
 mod part_1 {
     use itertools::Itertools;
 
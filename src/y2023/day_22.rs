@@ -1,19 +1,43 @@
-pub const DAY_STR: &str = "day_22";
+pub const DAY_STR: &str = "2023/day_22";
 
 pub use part_1::solution as part_1;
 pub use part_2::solution as part_2;
 
+use itertools::Itertools;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    for (n, line) in validate::lines(input) {
+        let coordinates = line.split(['~', ',']).collect_vec();
+        if coordinates.len() != 6 || line.matches('~').count() != 1 {
+            problems.at(
+                n,
+                format!("expected a brick like `1,0,1~1,2,1`, found `{}`", line),
+            );
+            continue;
+        }
+        for coordinate in coordinates {
+            problems.number::<u64>(n, coordinate);
+        }
+    }
+
+    problems.into_vec()
+}
+
 mod part_1 {
     use std::{collections::HashSet, fmt::Debug};
 
     use itertools::Itertools;
-    use nom::{
-        bytes::complete::tag,
-        character::complete::{self, newline},
-        multi::separated_list1,
-        sequence::{separated_pair, tuple},
-        IResult,
-    };
+    use nom::IResult;
+    use tracing::{debug, trace};
+
+    use crate::parsing::{self, lines, unsigned, vector3_pair};
 
     #[derive(Clone, Copy, PartialEq, Eq, Hash)]
     pub struct Pos3 {
@@ -86,26 +110,7 @@ mod part_1 {
     }
 
     pub fn parse_input(input: &str) -> IResult<&str, Vec<Cube>> {
-        let (input, semi_parsed) = separated_list1(
-            newline,
-            separated_pair(
-                tuple((
-                    complete::u64,
-                    tag(","),
-                    complete::u64,
-                    tag(","),
-                    complete::u64,
-                )),
-                tag("~"),
-                tuple((
-                    complete::u64,
-                    tag(","),
-                    complete::u64,
-                    tag(","),
-                    complete::u64,
-                )),
-            ),
-        )(input)?;
+        let (input, semi_parsed) = lines(vector3_pair(unsigned, '~'))(input)?;
 
         Ok((
             input,
@@ -113,14 +118,14 @@ mod part_1 {
                 .into_iter()
                 .map(|(lower, upper)| Cube {
                     lower: Pos3 {
-                        x: lower.0, // lower.1 = ","
-                        y: lower.2, // lower.3 = ","
-                        z: lower.4,
+                        x: lower.0,
+                        y: lower.1,
+                        z: lower.2,
                     },
                     upper: Pos3 {
-                        x: upper.0, // upper.1 = ","
-                        y: upper.2, // upper.3 = ","
-                        z: upper.4,
+                        x: upper.0,
+                        y: upper.1,
+                        z: upper.2,
                     },
                 })
                 .collect_vec(),
@@ -129,7 +134,7 @@ mod part_1 {
     }
 
     pub fn solution(input: String) -> usize {
-        let (_input, mut falling) = parse_input(&input).unwrap();
+        let mut falling = parsing::parse(parse_input, &input);
 
         // cubes.iter().for_each(|cube| {
         //     assert!(cube.lower.x <= cube.upper.x);
@@ -137,10 +142,8 @@ mod part_1 {
         //     assert!(cube.lower.z <= cube.upper.z);
         // });
 
-        // dbg!(&cubes);
         falling.sort_by_cached_key(|cube| -(cube.lower.z as i64));
-
-        // dbg!(&falling);
+        debug!(bricks = falling.len(), "parsed bricks");
 
         let mut settled = vec![];
 
@@ -149,7 +152,6 @@ mod part_1 {
             .into_iter()
             .rev()
             .map(|mut cube| loop {
-                // dbg!(cube);
                 cube.lower.z -= 1;
                 cube.upper.z -= 1;
                 let supports = settled
@@ -158,20 +160,16 @@ mod part_1 {
                     .filter(|other| cube.intersects(**other))
                     .copied()
                     .collect_vec();
-                // dbg!(&supports);
                 if cube.lower.z == 0 || !supports.is_empty() {
                     cube.lower.z += 1;
                     cube.upper.z += 1;
+                    trace!(?cube, ?supports, "brick settled");
                     settled.push(cube);
                     break supports;
                 }
             })
             .collect_vec();
 
-        // dbg!(&supported_by);
-
-        // dbg!(&settled);
-
         let key_cubes: HashSet<Cube> = HashSet::from_iter(supported_by.iter().filter_map(|sups| {
             if sups.len() == 1 {
                 Some(sups[0])
@@ -180,7 +178,10 @@ mod part_1 {
             }
         }));
 
-        // dbg!(&key_cubes);
+        debug!(
+            key_bricks = key_cubes.len(),
+            "found bricks that are sole supports"
+        );
 
         falling.len() - key_cubes.len()
     }
@@ -202,31 +203,27 @@ mod part_2 {
     use std::collections::{HashMap, HashSet};
 
     use itertools::Itertools;
+    use tracing::{debug, trace};
 
     use super::part_1::*;
 
+    use crate::parsing;
+
     fn disintegrate(
         cubes: &mut HashSet<Cube>,
         supported_by: &mut HashMap<Cube, HashSet<Cube>>,
         is_supporting: &mut HashMap<Cube, HashSet<Cube>>,
         cube: Cube,
     ) -> HashSet<Cube> {
-        // dbg!(&cubes);
-        // dbg!(cube);
         assert!(cubes.remove(&cube));
 
         if let Some(supporting) = is_supporting.remove(&cube) {
-            // dbg!(&supporting);
             for supported in supporting {
-                // dbg!(&supported);
                 let supports = supported_by.get_mut(&supported).expect("Should work?");
                 supports.remove(&cube);
-                // dbg!(&supports);
             }
         }
 
-        // dbg!(&supported_by);
-
         let unsupported = supported_by
             .iter()
             .filter_map(|(x_cube, x_supports)| {
@@ -242,7 +239,7 @@ mod part_2 {
             supported_by.remove(un_sup);
         }
 
-        // dbg!(&unsupported);
+        trace!(?cube, ?unsupported, "brick disintegrated");
 
         let mut disintegrated: HashSet<Cube> = unsupported
             .iter()
@@ -256,7 +253,7 @@ mod part_2 {
     }
 
     pub fn solution(input: String) -> usize {
-        let (_input, mut falling) = parse_input(&input).unwrap();
+        let mut falling = parsing::parse(parse_input, &input);
 
         // cubes.iter().for_each(|cube| {
         //     assert!(cube.lower.x <= cube.upper.x);
@@ -264,10 +261,8 @@ mod part_2 {
         //     assert!(cube.lower.z <= cube.upper.z);
         // });
 
-        // dbg!(&cubes);
         falling.sort_by_cached_key(|cube| -(cube.lower.z as i64));
-
-        // dbg!(&falling);
+        debug!(bricks = falling.len(), "parsed bricks");
 
         let ground_cube = Cube {
             lower: Pos3 { x: 0, y: 0, z: 0 },
@@ -282,7 +277,6 @@ mod part_2 {
 
         let supported_by: HashMap<Cube, HashSet<Cube>> =
             HashMap::from_iter(falling.clone().into_iter().rev().map(|mut cube| loop {
-                // dbg!(cube);
                 cube.lower.z -= 1;
                 cube.upper.z -= 1;
                 let supports: HashSet<Cube> = HashSet::from_iter(
@@ -292,24 +286,22 @@ mod part_2 {
                         .filter(|other| cube.intersects(**other))
                         .copied(),
                 );
-                // dbg!(&supports);
                 if cube.lower.z == 0 {
                     cube.lower.z += 1;
                     cube.upper.z += 1;
+                    trace!(?cube, "brick settled on the ground");
                     settled.push(cube);
                     break (cube, HashSet::from_iter(vec![ground_cube]));
                 } else if !supports.is_empty() {
                     cube.lower.z += 1;
                     cube.upper.z += 1;
+                    trace!(?cube, ?supports, "brick settled");
                     settled.push(cube);
                     break (cube, supports);
                 }
             }));
 
         let cubes: HashSet<Cube> = HashSet::from_iter(settled.clone());
-        // dbg!(&settled);
-
-        // dbg!(&supported_by);
 
         let is_supporting: HashMap<Cube, HashSet<Cube>> =
             supported_by
@@ -325,8 +317,6 @@ mod part_2 {
                     is_supporting
                 });
 
-        // dbg!(&is_supporting);
-
         let others = cubes
             .iter()
             .map(|starting_cube| {
@@ -340,13 +330,15 @@ mod part_2 {
                     &mut is_supporting,
                     *starting_cube,
                 );
-                // dbg!(disintegrated);
+                debug!(
+                    ?starting_cube,
+                    falling = disintegrated.len() - 1,
+                    "chain reaction finished"
+                );
                 disintegrated.len() - 1
             })
             .collect_vec();
 
-        // dbg!(&others);
-
         others.iter().sum()
         // todo!()
     }
@@ -1,15 +1,39 @@
-pub const DAY_STR: &str = "day_23";
+pub const DAY_STR: &str = "2023/day_23";
 
 pub use part_1::solution as part_1;
 pub use part_2::solution as part_2;
 
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    let lines = validate::lines(input).collect::<Vec<_>>();
+    validate::grid(&mut problems, &lines, "#.<>^v");
+
+    // the start and the end are the only paths in the top and bottom rows
+    for (row, side) in [(lines.first(), "top"), (lines.last(), "bottom")] {
+        if let Some((n, line)) = row {
+            if line.matches('.').count() != 1 {
+                problems.at(*n, format!("the {} row should have exactly one path", side));
+            }
+        }
+    }
+
+    problems.into_vec()
+}
+
 mod part_1 {
 
     use itertools::Itertools;
 
     use petgraph::algo::all_simple_paths;
-    // use petgraph::dot::Dot;
+    use petgraph::dot::Dot;
     use petgraph::prelude::*;
+    use tracing::{debug, trace};
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub enum Tile {
@@ -113,9 +137,6 @@ mod part_1 {
                         .filter(|t| *t != Wall)
                         .collect_vec();
                     if non_walls.len() > 2 {
-                        // dbg!(tile);
-                        // dbg!(y);
-                        // dbg!(x);
                         return Some(Vertex { y, x });
                     }
                 }
@@ -126,13 +147,11 @@ mod part_1 {
         vertices.push(starting_vertex);
         vertices.push(ending_vertex);
 
-        // dbg!(&vertices);
+        debug!(vertices = vertices.len(), "found junctions");
 
         let edges = vertices
             .iter()
             .flat_map(|vertex| {
-                // dbg!(vertex);
-
                 let starting_dirs = if vertex == &starting_vertex {
                     vec![Dir::D]
                 } else if vertex == &ending_vertex {
@@ -149,10 +168,7 @@ mod part_1 {
                         .collect_vec()
                 };
 
-                // dbg!(&starting_dirs);
-
-                // dbg!(&adjacent_edges);
-                // vec![0]
+                trace!(?vertex, ?starting_dirs, "walking out of junction");
                 starting_dirs
                     .into_iter()
                     .filter_map(|start_dir| {
@@ -162,7 +178,6 @@ mod part_1 {
                             Dir::L => (vertex.y, vertex.x - 1),
                             Dir::R => (vertex.y, vertex.x + 1),
                         };
-                        // dbg!(start_dir);
 
                         let starting_tile = grid[y][x];
                         if match start_dir {
@@ -179,7 +194,6 @@ mod part_1 {
                         let mut num_steps = 0;
                         loop {
                             num_steps += 1;
-                            // dbg!(last_dir);
                             let mut non_wall_tiles = 0;
 
                             let open_squares = vec![Dir::U, Dir::D, Dir::L, Dir::R]
@@ -201,8 +215,6 @@ mod part_1 {
                                         Dir::R => (y, x + 1),
                                     };
                                     let n_tile = grid[n_y][n_x];
-                                    // dbg!(dir);
-                                    // dbg!(n_tile);
                                     if n_tile == Wall {
                                         None
                                     } else if dir == Dir::U && n_tile == DSlope
@@ -221,14 +233,10 @@ mod part_1 {
                                 })
                                 .collect_vec();
 
-                            // dbg!(non_wall_tiles);
-                            // dbg!(&open_squares);
-
                             if non_wall_tiles == 0 || open_squares.is_empty() {
                                 break None;
                             } else if non_wall_tiles > 1 {
-                                // dbg!(y);
-                                // dbg!(x);
+                                trace!(?vertex, y, x, num_steps, "reached junction");
                                 break Some(Edge {
                                     start: *vertex,
                                     end: Vertex { y, x },
@@ -256,19 +264,13 @@ mod part_1 {
             })
             .collect_vec();
 
-        // dbg!(&edges);
-
-        // let num_vertices = vertices.len();
-        // dbg!(num_vertices);
-        // let num_edges = edges.len();
-        // dbg!(num_edges);
+        debug!(edges = edges.len(), "found paths between junctions");
 
         let graph = DiGraphMap::<Vertex, usize>::from_edges(
             edges.iter().map(|e| (e.start, e.end, e.length)),
         );
 
-        // let g = Dot::with_config(&graph, &[]);
-        // dbg!(g);
+        trace!(graph = ?Dot::with_config(&graph, &[]), "built junction graph");
 
         let paths: Vec<Vec<_>> =
             all_simple_paths(&graph, starting_vertex, ending_vertex, 0, None).collect_vec();
@@ -283,7 +285,7 @@ mod part_1 {
             })
             .collect_vec();
 
-        // dbg!(&path_lengths);
+        debug!(paths = path_lengths.len(), "found all simple paths");
 
         *path_lengths.iter().max().unwrap()
     }
@@ -305,8 +307,9 @@ mod part_2 {
     use itertools::Itertools;
 
     use petgraph::algo::all_simple_paths;
-    // use petgraph::dot::Dot;
+    use petgraph::dot::Dot;
     use petgraph::prelude::*;
+    use tracing::{debug, trace};
 
     use super::part_1::*;
 
@@ -360,9 +363,6 @@ mod part_2 {
                         .filter(|t| *t != Wall)
                         .collect_vec();
                     if non_walls.len() > 2 {
-                        // dbg!(tile);
-                        // dbg!(y);
-                        // dbg!(x);
                         return Some(Vertex { y, x });
                     }
                 }
@@ -373,13 +373,11 @@ mod part_2 {
         vertices.push(starting_vertex);
         vertices.push(ending_vertex);
 
-        // dbg!(&vertices);
+        debug!(vertices = vertices.len(), "found junctions");
 
         let edges = vertices
             .iter()
             .flat_map(|vertex| {
-                // dbg!(vertex);
-
                 let starting_dirs = if vertex == &starting_vertex {
                     vec![Dir::D]
                 } else if vertex == &ending_vertex {
@@ -396,7 +394,7 @@ mod part_2 {
                         .collect_vec()
                 };
 
-                // dbg!(&starting_dirs);
+                trace!(?vertex, ?starting_dirs, "walking out of junction");
                 starting_dirs
                     .into_iter()
                     .filter_map(|start_dir| {
@@ -406,7 +404,6 @@ mod part_2 {
                             Dir::L => (vertex.y, vertex.x - 1),
                             Dir::R => (vertex.y, vertex.x + 1),
                         };
-                        // dbg!(start_dir);
 
                         // let starting_tile = grid[y][x];
                         // if match start_dir {
@@ -423,7 +420,6 @@ mod part_2 {
                         let mut num_steps = 0;
                         loop {
                             num_steps += 1;
-                            // dbg!(last_dir);
                             let mut non_wall_tiles = 0;
 
                             let open_squares = vec![Dir::U, Dir::D, Dir::L, Dir::R]
@@ -445,8 +441,6 @@ mod part_2 {
                                         Dir::R => (y, x + 1),
                                     };
                                     let n_tile = grid[n_y][n_x];
-                                    // dbg!(dir);
-                                    // dbg!(n_tile);
                                     if n_tile == Wall {
                                         None
                                     } else {
@@ -466,14 +460,10 @@ mod part_2 {
                                 })
                                 .collect_vec();
 
-                            // dbg!(non_wall_tiles);
-                            // dbg!(&open_squares);
-
                             if non_wall_tiles == 0 || open_squares.is_empty() {
                                 break None;
                             } else if non_wall_tiles > 1 {
-                                // dbg!(y);
-                                // dbg!(x);
+                                trace!(?vertex, y, x, num_steps, "reached junction");
                                 break Some(Edge {
                                     start: *vertex,
                                     end: Vertex { y, x },
@@ -501,19 +491,13 @@ mod part_2 {
             })
             .collect_vec();
 
-        // dbg!(&edges);
-
-        // let num_vertices = vertices.len();
-        // dbg!(num_vertices);
-        // let num_edges = edges.len();
-        // dbg!(num_edges);
+        debug!(edges = edges.len(), "found paths between junctions");
 
         let graph = DiGraphMap::<Vertex, usize>::from_edges(
             edges.iter().map(|e| (e.start, e.end, e.length)),
         );
 
-        // let g = Dot::with_config(&graph, &[]);
-        // dbg!(g);
+        trace!(graph = ?Dot::with_config(&graph, &[]), "built junction graph");
 
         let paths: Vec<Vec<_>> =
             all_simple_paths(&graph, starting_vertex, ending_vertex, 0, None).collect_vec();
@@ -528,7 +512,7 @@ mod part_2 {
             })
             .collect_vec();
 
-        // dbg!(&path_lengths);
+        debug!(paths = path_lengths.len(), "found all simple paths");
 
         *path_lengths.iter().max().unwrap()
     }
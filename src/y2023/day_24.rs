@@ -1,19 +1,44 @@
-pub const DAY_STR: &str = "day_24";
+pub const DAY_STR: &str = "2023/day_24";
 
 pub use part_1::solution as part_1;
 pub use part_2::solution as part_2;
 
+use itertools::Itertools;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    for (n, line) in validate::lines(input) {
+        let Some((position, velocity)) = line.split_once('@') else {
+            problems.at(n, "expected a position and a velocity separated by `@`");
+            continue;
+        };
+        for vector in [position, velocity] {
+            let values = vector.split(',').collect_vec();
+            if values.len() != 3 {
+                problems.at(n, format!("`{}` should have three values", vector.trim()));
+            }
+            for value in values {
+                problems.number::<i64>(n, value);
+            }
+        }
+    }
+
+    problems.into_vec()
+}
+
 mod part_1 {
     use std::fmt::Debug;
 
     use itertools::Itertools;
-    use nom::{
-        bytes::complete::tag,
-        character::complete::{self, newline},
-        multi::separated_list1,
-        sequence::tuple,
-        IResult,
-    };
+    use nom::IResult;
+
+    use crate::parsing::{self, lines, signed, vector3_pair};
 
     #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct I64Vec3 {
@@ -37,28 +62,13 @@ mod part_1 {
     }
 
     pub fn parse_balls(input: &str) -> IResult<&str, Vec<Ball>> {
-        let (input, separated) = separated_list1(
-            newline,
-            tuple((
-                complete::i64,
-                tag(", "),
-                complete::i64,
-                tag(", "),
-                complete::i64,
-                tag(" @ "),
-                complete::i64,
-                tag(", "),
-                complete::i64,
-                tag(", "),
-                complete::i64,
-            )),
-        )(input)?;
+        let (input, separated) = lines(vector3_pair(signed, '@'))(input)?;
 
         Ok((
             input,
             separated
                 .into_iter()
-                .map(|(px, _, py, _, pz, _, vx, _, vy, _, vz)| Ball {
+                .map(|((px, py, pz), (vx, vy, vz))| Ball {
                     pos: I64Vec3 {
                         x: px,
                         y: py,
@@ -75,7 +85,7 @@ mod part_1 {
     }
 
     pub fn solution(input: String) -> usize {
-        let (_input, balls) = parse_balls(&input).unwrap();
+        let balls = parsing::parse(parse_balls, &input);
 
         // dbg!(_input);
 
@@ -180,8 +190,10 @@ mod part_2 {
 
     use super::part_1::*;
 
+    use crate::parsing;
+
     pub fn solution(input: String) -> usize {
-        let (_input, balls) = parse_balls(&input).unwrap();
+        let balls = parsing::parse(parse_balls, &input);
 
         // dbg!(_input);
 
@@ -1,27 +1,45 @@
-pub const DAY_STR: &str = "day_25";
+pub const DAY_STR: &str = "2023/day_25";
 
 pub use part_1::solution as part_1;
 pub use part_2::solution as part_2;
 
+use std::iter;
+
+use crate::validate::{self, Problem, Problems};
+
+pub fn validate(input: &str) -> Vec<Problem> {
+    let mut problems = Problems::default();
+    if !validate::not_empty(&mut problems, input) {
+        return problems.into_vec();
+    }
+
+    for (n, line) in validate::lines(input) {
+        let Some((component, connected)) = line.split_once(": ") else {
+            problems.at(n, "expected a component and the components it connects to");
+            continue;
+        };
+        for name in iter::once(component).chain(connected.split(' ')) {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase()) {
+                problems.at(n, format!("`{}` is not a component name", name));
+            }
+        }
+    }
+
+    problems.into_vec()
+}
+
 mod part_1 {
     use itertools::Itertools;
-    use nom::{
-        bytes::complete::tag,
-        character::complete::{alpha1, newline},
-        multi::separated_list1,
-        sequence::separated_pair,
-        IResult,
-    };
+    use nom::{character::complete::alpha1, IResult};
 
     use petgraph::{algo, prelude::*};
 
     use rand::prelude::*;
 
+    use crate::parsing::{self, key_values, lines};
+
     pub fn parse_edges(input: &str) -> IResult<&str, Vec<(&str, Vec<&str>)>> {
-        separated_list1(
-            newline,
-            separated_pair(alpha1, tag(": "), separated_list1(tag(" "), alpha1)),
-        )(input)
+        lines(key_values(alpha1))(input)
     }
 
     pub fn min_cut_set_size(graph: &UnGraphMap<&str, ()>) -> usize {
@@ -81,7 +99,7 @@ mod part_1 {
     }
 
     pub fn solution(input: String) -> usize {
-        let (_input, vertex_map) = parse_edges(&input).unwrap();
+        let vertex_map = parsing::parse(parse_edges, &input);
 
         // dbg!(&edges);
 